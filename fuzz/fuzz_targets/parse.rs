@@ -0,0 +1,44 @@
+// Run with `cargo +nightly fuzz run parse`
+
+#![no_main]
+
+use editorconfig_rs::EditorConfigHandle;
+use libfuzzer_sys::fuzz_target;
+use std::{env, fs, process, str};
+
+const CONFIG_FILENAME: &str = ".editorconfig.fuzz";
+
+fuzz_target!(|input: (Vec<u8>, String)| {
+    // Config files on disk aren't necessarily valid UTF-8
+    let (config, target) = input;
+
+    // `parse` requires paths without NUL bytes
+    if target.contains('\0') {
+        return;
+    }
+
+    // libeditorconfig only reads config files from disk
+    let fuzz_dir = env::temp_dir().join(format!("editorconfig-rs-fuzz-{}", process::id()));
+    fs::create_dir_all(&fuzz_dir).unwrap();
+    fs::write(fuzz_dir.join(CONFIG_FILENAME), &config).unwrap();
+
+    if let Ok(config) = str::from_utf8(&config) {
+        let _ = editorconfig_rs::parse_config_document(config);
+    }
+
+    let mut handle = EditorConfigHandle::new().unwrap();
+    handle.set_config_filename(CONFIG_FILENAME);
+
+    let target_path = fuzz_dir.join(target.trim_start_matches('/'));
+    match handle.parse(&target_path) {
+        Some(err) => {
+            let _ = editorconfig_rs::get_error_message(err);
+            let _ = handle.get_error_file();
+        }
+        None => {
+            // Rules that aren't valid UTF-8 are skipped
+            let rules = handle.get_rules();
+            assert!(rules.len() <= handle.get_rule_count() as usize);
+        }
+    }
+});
@@ -0,0 +1,72 @@
+use std::{error::Error, fmt, os::raw::c_int};
+
+use crate::{EditorConfigHandle, Version};
+
+/// Errors returned by [`EditorConfigHandleBuilder::build`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandleError {
+    /// `libeditorconfig` failed to create a handle
+    InitError,
+    /// The configuration filename contains a NUL byte
+    InvalidConfigFilename(String),
+}
+
+impl fmt::Display for HandleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandleError::InitError => write!(f, "Failed to create EditorConfigHandle"),
+            HandleError::InvalidConfigFilename(filename) => {
+                write!(f, "Invalid config filename: {:?}", filename)
+            }
+        }
+    }
+}
+
+impl Error for HandleError {}
+
+/// Builder for an [`EditorConfigHandle`] returned by
+/// [`EditorConfigHandle::builder`]
+///
+/// Unlike [`EditorConfigHandle::set_config_filename`], invalid input never
+/// panics but is returned as a [`HandleError`] from
+/// [`EditorConfigHandleBuilder::build`].
+#[derive(Debug, Clone, Default)]
+pub struct EditorConfigHandleBuilder {
+    config_filename: Option<String>,
+    version: Option<Version<c_int>>,
+}
+
+impl EditorConfigHandleBuilder {
+    /// Sets a custom EditorConfig configuration filename
+    #[must_use]
+    pub fn config_filename(mut self, filename: &str) -> Self {
+        self.config_filename = Some(filename.to_owned());
+        self
+    }
+
+    /// Sets the EditorConfig [version](Version)
+    #[must_use]
+    pub fn version<T: Into<c_int>>(mut self, version: Version<T>) -> Self {
+        self.version = Some(Version {
+            major: version.major.into(),
+            minor: version.minor.into(),
+            patch: version.patch.into(),
+        });
+        self
+    }
+
+    /// Creates the [`EditorConfigHandle`]
+    pub fn build(self) -> Result<EditorConfigHandle, HandleError> {
+        let mut handle = EditorConfigHandle::new().map_err(|_| HandleError::InitError)?;
+
+        if let Some(filename) = self.config_filename {
+            handle.try_set_config_filename(&filename)?;
+        }
+
+        if let Some(version) = self.version {
+            handle.set_version(version);
+        }
+
+        Ok(handle)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{properties::names, Charset, EndOfLine, IndentStyle, Rules};
+
+/// Indentation settings of a [`PathClass`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Indentation {
+    /// Whether to indent with tabs or spaces
+    pub style: IndentStyle,
+    /// Number of columns per indentation level
+    pub size: u32,
+    /// Number of columns a tab character takes up
+    pub tab_width: u32,
+}
+
+/// Summary of the [rules](Rules) an editor needs to configure a buffer,
+/// returned by [`classify_path`](crate::classify_path)
+///
+/// Unset or invalid rules, including ones set to `unset`, are filled in
+/// from [`PathClass::default`]: indentation with 4 spaces, `lf` line endings,
+/// `utf-8` and no final newline. Use [`PathClass::with_defaults`] to fill
+/// them in with an editor's own defaults instead.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use editorconfig_rs::{IndentStyle, PathClass, Rules};
+///
+/// let mut rules = HashMap::new();
+/// rules.insert("indent_style".to_owned(), "tab".to_owned());
+/// rules.insert("tab_width".to_owned(), "8".to_owned());
+/// let path_class = PathClass::from(&Rules::from(rules));
+/// assert_eq!(path_class.indent.style, IndentStyle::Tab);
+/// assert_eq!(path_class.indent.size, 8);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathClass {
+    /// Indentation from `indent_style`, `indent_size` and `tab_width`
+    pub indent: Indentation,
+    /// Line ending from `end_of_line`
+    pub eol: EndOfLine,
+    /// Character set from `charset`
+    pub charset: Charset,
+    /// Whether `insert_final_newline` is `true`
+    pub final_newline: bool,
+}
+
+impl PathClass {
+    /// Returns the [rules](Rules) as a [`PathClass`], filling in unset or
+    /// invalid rules, including ones set to `unset`, from `defaults`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use editorconfig_rs::{EndOfLine, PathClass, Rules};
+    ///
+    /// let editor_defaults = PathClass {
+    ///     eol: EndOfLine::Crlf,
+    ///     final_newline: true,
+    ///     ..PathClass::default()
+    /// };
+    ///
+    /// let mut rules = HashMap::new();
+    /// rules.insert("end_of_line".to_owned(), "unset".to_owned());
+    /// rules.insert("insert_final_newline".to_owned(), "false".to_owned());
+    /// let path_class = PathClass::with_defaults(&Rules::from(rules), &editor_defaults);
+    /// assert_eq!(path_class.eol, EndOfLine::Crlf);
+    /// assert!(!path_class.final_newline);
+    /// ```
+    ///
+    #[must_use]
+    pub fn with_defaults(rules: &Rules, defaults: &PathClass) -> Self {
+        let tab_width = rules
+            .effective_tab_width()
+            .unwrap_or(defaults.indent.tab_width);
+
+        // Indenting by the tab width uses the default tab width, not the
+        // default indent size, if `tab_width` isn't set
+        let size = match rules.effective_indent_size() {
+            Some(size) => size,
+            None if rules.indents_by_tab_width() => tab_width,
+            None => defaults.indent.size,
+        };
+
+        let indent = Indentation {
+            style: rules.indent_style().unwrap_or(defaults.indent.style),
+            size,
+            tab_width,
+        };
+
+        let final_newline = match rules.get(names::INSERT_FINAL_NEWLINE).map(String::as_str) {
+            Some("true") => true,
+            Some("false") => false,
+            _ => defaults.final_newline,
+        };
+
+        PathClass {
+            indent,
+            eol: rules.end_of_line().unwrap_or(defaults.eol),
+            charset: rules.charset().unwrap_or(defaults.charset),
+            final_newline,
+        }
+    }
+}
+
+impl Default for PathClass {
+    fn default() -> Self {
+        PathClass {
+            indent: Indentation {
+                style: IndentStyle::Space,
+                size: 4,
+                tab_width: 4,
+            },
+            eol: EndOfLine::Lf,
+            charset: Charset::Utf8,
+            final_newline: false,
+        }
+    }
+}
+
+impl From<&Rules> for PathClass {
+    fn from(rules: &Rules) -> Self {
+        PathClass::with_defaults(rules, &PathClass::default())
+    }
+}
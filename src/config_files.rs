@@ -0,0 +1,151 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::document::parse_config_document;
+
+/// Default name of EditorConfig configuration files
+const DEFAULT_CONFIG_FILENAME: &str = ".editorconfig";
+
+/// Returns the configuration file closest to an absolute path
+///
+/// Walks up from the directory containing `absolute_path` and returns the
+/// first file named `config_filename`, or `".editorconfig"` if
+/// `config_filename` is [`None`]. Returns [`None`] if there is no such file
+/// up to the root directory.
+///
+/// # Example
+///
+/// ```
+/// let test_file_path = std::fs::canonicalize("tests/editorconfig.rs").unwrap();
+/// let config_file_path = editorconfig_rs::find_nearest_config(test_file_path, None);
+/// # assert_eq!(config_file_path, Some(std::fs::canonicalize("tests/.editorconfig").unwrap()));
+/// ```
+///
+#[must_use]
+pub fn find_nearest_config<P: AsRef<Path>>(
+    absolute_path: P,
+    config_filename: Option<&str>,
+) -> Option<PathBuf> {
+    let config_filename = config_filename.unwrap_or(DEFAULT_CONFIG_FILENAME);
+
+    absolute_path
+        .as_ref()
+        .parent()?
+        .ancestors()
+        .map(|dir| dir.join(config_filename))
+        .find(|config_file_path| config_file_path.is_file())
+}
+
+/// Returns `true` if there is an `".editorconfig"` file in any directory
+/// above an absolute path
+///
+/// [`EditorConfigHandle::parse`](crate::EditorConfigHandle::parse) finds zero
+/// rules both when there is no configuration file at all and when none of
+/// its sections match, which this distinguishes.
+///
+/// # Example
+///
+/// ```
+/// let test_file_path = std::fs::canonicalize("tests/editorconfig.rs").unwrap();
+/// let has_config = editorconfig_rs::has_any_config(test_file_path);
+/// # assert!(has_config);
+/// ```
+///
+#[must_use]
+pub fn has_any_config<P: AsRef<Path>>(absolute_path: P) -> bool {
+    find_nearest_config(absolute_path, None).is_some()
+}
+
+/// A `[glob]` section found by [`all_sections`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionInfo {
+    /// The glob between the square brackets
+    pub glob: String,
+    /// Path of the configuration file containing the section
+    pub config_file: PathBuf,
+    /// Line number of the section header, starting at 1
+    pub line: usize,
+    /// Number of properties in the section
+    pub property_count: usize,
+}
+
+/// Returns the sections of all configuration files in a directory tree
+///
+/// Reads all files named `config_filename`, or `".editorconfig"` if
+/// `config_filename` is [`None`]. Sections are sorted by configuration file path and line number.
+/// Directories and files that can't be read are skipped and symbolic links to
+/// directories aren't followed.
+///
+/// # Example
+///
+/// ```
+/// let sections = editorconfig_rs::all_sections("tests", None);
+/// for section in &sections {
+///     println!("{}:{}: [{}]", section.config_file.display(), section.line, section.glob);
+/// }
+/// # assert_eq!(sections[0].glob, "*");
+/// ```
+///
+#[must_use]
+pub fn all_sections<P: AsRef<Path>>(root: P, config_filename: Option<&str>) -> Vec<SectionInfo> {
+    let config_filename = config_filename.unwrap_or(DEFAULT_CONFIG_FILENAME);
+    let mut config_file_paths = Vec::new();
+    find_config_files(root.as_ref(), config_filename, &mut config_file_paths);
+    config_file_paths.sort();
+
+    let mut sections = Vec::new();
+    for config_file_path in config_file_paths {
+        let config = match fs::read_to_string(&config_file_path) {
+            Ok(config) => config,
+            Err(_) => continue,
+        };
+
+        let document = parse_config_document(&config);
+        sections.extend(document.sections.into_iter().map(|section| SectionInfo {
+            glob: section.glob,
+            config_file: config_file_path.clone(),
+            line: section.line,
+            property_count: section.properties.len(),
+        }));
+    }
+
+    sections
+}
+
+fn find_config_files(dir: &Path, config_filename: &str, config_file_paths: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(_) => continue,
+        };
+
+        if file_type.is_dir() {
+            find_config_files(&entry.path(), config_filename, config_file_paths);
+        } else if entry.file_name() == config_filename {
+            config_file_paths.push(entry.path());
+        }
+    }
+}
+
+/// Returns `true` if any configuration file above an absolute path contains
+/// `root = true`
+///
+/// Unreadable configuration files are skipped like `libeditorconfig` does.
+pub(crate) fn stops_at_root(absolute_path: &Path, config_filename: Option<&str>) -> bool {
+    let config_filename = config_filename.unwrap_or(DEFAULT_CONFIG_FILENAME);
+    let dir = match absolute_path.parent() {
+        Some(dir) => dir,
+        None => return false,
+    };
+
+    dir.ancestors()
+        .filter_map(|dir| fs::read_to_string(dir.join(config_filename)).ok())
+        .any(|config| parse_config_document(&config).is_root())
+}
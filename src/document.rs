@@ -0,0 +1,132 @@
+/// A `key = value` pair in an EditorConfig file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProperty {
+    /// Property name as written in the file
+    pub key: String,
+    /// Property value as written in the file
+    pub value: String,
+    /// Line number, starting at 1
+    pub line: usize,
+}
+
+/// A `[glob]` section in an EditorConfig file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigSection {
+    /// The glob between the square brackets
+    pub glob: String,
+    /// Line number of the section header, starting at 1
+    pub line: usize,
+    /// Properties in this section
+    pub properties: Vec<ConfigProperty>,
+}
+
+/// Kind of a line in an EditorConfig file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineKind {
+    /// An empty or whitespace-only line
+    Blank,
+    /// A comment starting with `#`
+    HashComment,
+    /// A comment starting with `;`
+    SemicolonComment,
+    /// A `[glob]` section header
+    Section,
+    /// A `key = value` pair
+    Property,
+    /// A line that is none of the above
+    Invalid,
+}
+
+/// Structure of an EditorConfig file as returned by [`parse_config_document`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDocument {
+    /// Properties before the first section, e.g. `root = true`
+    pub preamble: Vec<ConfigProperty>,
+    /// All sections in the order they appear in the file
+    pub sections: Vec<ConfigSection>,
+    /// Kind of every line, where line number `n` is at index `n - 1`
+    pub lines: Vec<LineKind>,
+}
+
+impl ConfigDocument {
+    /// Returns `true` if the preamble contains `root = true`
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.preamble.iter().any(|property| {
+            property.key.eq_ignore_ascii_case("root") && property.value.eq_ignore_ascii_case("true")
+        })
+    }
+}
+
+/// Parses the contents of an EditorConfig file into its preamble and sections
+///
+/// Unlike [`EditorConfigHandle::parse`](crate::EditorConfigHandle::parse),
+/// this doesn't resolve any rules for a path, but returns the structure of
+/// the file itself with keys and values as written, and the
+/// [kind](LineKind) of every line. Invalid lines are otherwise skipped;
+/// `libeditorconfig` reports them as
+/// [`ParseError::LineError`](crate::ParseError::LineError).
+///
+/// # Example
+///
+/// ```
+/// let config = "root = true\n\n[*.rs]\nindent_style = space\n";
+/// let document = editorconfig_rs::parse_config_document(config);
+/// assert!(document.is_root());
+/// assert_eq!(document.sections[0].glob, "*.rs");
+/// assert_eq!(document.sections[0].properties[0].line, 4);
+/// ```
+///
+#[must_use]
+pub fn parse_config_document(text: &str) -> ConfigDocument {
+    let mut document = ConfigDocument::default();
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    for (line_index, line) in text.lines().enumerate() {
+        let line_num = line_index + 1;
+        let line_kind = parse_line(&mut document, line.trim(), line_num);
+        document.lines.push(line_kind);
+    }
+
+    document
+}
+
+fn parse_line(document: &mut ConfigDocument, line: &str, line_num: usize) -> LineKind {
+    if line.is_empty() {
+        return LineKind::Blank;
+    } else if line.starts_with('#') {
+        return LineKind::HashComment;
+    } else if line.starts_with(';') {
+        return LineKind::SemicolonComment;
+    }
+
+    if let Some(section) = line.strip_prefix('[') {
+        return match section.rfind(']') {
+            Some(end) => {
+                document.sections.push(ConfigSection {
+                    glob: section[..end].to_owned(),
+                    line: line_num,
+                    properties: Vec::new(),
+                });
+                LineKind::Section
+            }
+            None => LineKind::Invalid,
+        };
+    }
+
+    match line.find(|c| c == '=' || c == ':') {
+        Some(delimiter) => {
+            let property = ConfigProperty {
+                key: line[..delimiter].trim_end().to_owned(),
+                value: line[delimiter + 1..].trim_start().to_owned(),
+                line: line_num,
+            };
+            match document.sections.last_mut() {
+                Some(section) => section.properties.push(property),
+                None => document.preamble.push(property),
+            }
+            LineKind::Property
+        }
+        None => LineKind::Invalid,
+    }
+}
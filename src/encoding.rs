@@ -0,0 +1,174 @@
+use std::{
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{Charset, Rules};
+
+/// Error returned by [`encode_with_charset`] when the content contains a
+/// character that can't be encoded in `latin1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeError {
+    /// The character that can't be encoded
+    pub character: char,
+    /// Byte index of the character in the content
+    pub index: usize,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot encode {:?} at byte {} as latin1",
+            self.character, self.index
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+/// Mismatch between the `charset` rule and the byte order mark of a file,
+/// returned by [`charset_conflict`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharsetConflict {
+    /// The charset declared by the `charset` rule
+    pub declared: Charset,
+    /// The charset indicated by the file's byte order mark or [`None`] if the
+    /// file doesn't start with one
+    pub detected: Option<Charset>,
+}
+
+/// Encodes `content` for writing a file with the given [`Charset`]
+///
+/// Prepends a byte order mark for `utf-8-bom`, `utf-16be` and `utf-16le`.
+/// Returns an [`EncodeError`] for `latin1` if `content` contains a character
+/// above `U+00FF`.
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::Charset;
+///
+/// let bytes = editorconfig_rs::encode_with_charset("é\n", Charset::Utf16Le).unwrap();
+/// assert_eq!(bytes, [0xFF, 0xFE, 0xE9, 0x00, 0x0A, 0x00]);
+/// let bytes = editorconfig_rs::encode_with_charset("é\n", Charset::Latin1).unwrap();
+/// assert_eq!(bytes, [0xE9, 0x0A]);
+/// ```
+///
+pub fn encode_with_charset(content: &str, charset: Charset) -> Result<Vec<u8>, EncodeError> {
+    let bytes = match charset {
+        Charset::Latin1 => content
+            .char_indices()
+            .map(|(index, character)| {
+                u8::try_from(u32::from(character)).map_err(|_| EncodeError { character, index })
+            })
+            .collect::<Result<_, _>>()?,
+        Charset::Utf8 => content.as_bytes().to_vec(),
+        Charset::Utf8Bom => [&[0xEF, 0xBB, 0xBF], content.as_bytes()].concat(),
+        Charset::Utf16Be => [0xFEFF]
+            .iter()
+            .copied()
+            .chain(content.encode_utf16())
+            .flat_map(u16::to_be_bytes)
+            .collect(),
+        Charset::Utf16Le => [0xFEFF]
+            .iter()
+            .copied()
+            .chain(content.encode_utf16())
+            .flat_map(u16::to_le_bytes)
+            .collect(),
+    };
+
+    Ok(bytes)
+}
+
+/// Decodes `bytes` read from a file with the given [`Charset`]
+///
+/// The inverse of [`encode_with_charset`]: strips the byte order mark that
+/// `utf-8-bom`, `utf-16be` and `utf-16le` may start with. Content without a
+/// charset is decoded as UTF-8 and keeps any byte order mark as `U+FEFF`.
+/// Returns [`None`] if `bytes` aren't valid in the charset.
+pub(crate) fn decode_with_charset(bytes: &[u8], charset: Option<Charset>) -> Option<String> {
+    let utf16 = |bytes: &[u8], from_bytes: fn([u8; 2]) -> u16| {
+        if bytes.len() % 2 != 0 {
+            return None;
+        }
+        let units: Vec<u16> = bytes
+            .chunks(2)
+            .map(|pair| from_bytes([pair[0], pair[1]]))
+            .collect();
+        let mut content = String::from_utf16(&units).ok()?;
+        if content.starts_with('\u{FEFF}') {
+            content.remove(0);
+        }
+        Some(content)
+    };
+
+    match charset {
+        None | Some(Charset::Utf8) => String::from_utf8(bytes.to_vec()).ok(),
+        Some(Charset::Utf8Bom) => {
+            let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+            String::from_utf8(bytes.to_vec()).ok()
+        }
+        Some(Charset::Latin1) => Some(bytes.iter().map(|&byte| char::from(byte)).collect()),
+        Some(Charset::Utf16Be) => utf16(bytes, u16::from_be_bytes),
+        Some(Charset::Utf16Le) => utf16(bytes, u16::from_le_bytes),
+    }
+}
+
+/// Checks the byte order mark of the file at `path` against the `charset`
+/// rule
+///
+/// Only reads the leading bytes of the file. Returns a [`CharsetConflict`]
+/// if the file starts with a byte order mark for a different charset, or if
+/// `utf-8-bom`, `utf-16be` or `utf-16le` is declared but the file doesn't
+/// start with the matching byte order mark. Returns [`None`] if the rule is
+/// unset or invalid, since `latin1` and `utf-8` can't be told apart without
+/// a byte order mark.
+///
+/// # Example
+///
+/// ```
+/// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+/// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+/// let err = handle.parse(&test_file_path);
+/// # assert!(err.is_none());
+/// let rules = handle.get_rules();
+/// if let Some(conflict) = editorconfig_rs::charset_conflict(&test_file_path, &rules).unwrap() {
+///     eprintln!("Warning: expected {:?}, found {:?}", conflict.declared, conflict.detected);
+/// }
+/// ```
+///
+pub fn charset_conflict<P: AsRef<Path>>(
+    path: P,
+    rules: &Rules,
+) -> io::Result<Option<CharsetConflict>> {
+    let declared = match rules.charset() {
+        Some(declared) => declared,
+        None => return Ok(None),
+    };
+
+    let mut leading_bytes = Vec::with_capacity(3);
+    File::open(path)?.take(3).read_to_end(&mut leading_bytes)?;
+
+    let detected = match leading_bytes.as_slice() {
+        [0xEF, 0xBB, 0xBF, ..] => Some(Charset::Utf8Bom),
+        [0xFE, 0xFF, ..] => Some(Charset::Utf16Be),
+        [0xFF, 0xFE, ..] => Some(Charset::Utf16Le),
+        _ => None,
+    };
+
+    let conflict = match (declared, detected) {
+        (Charset::Latin1 | Charset::Utf8, None) => false,
+        (_, detected) => detected != Some(declared),
+    };
+
+    Ok(if conflict {
+        Some(CharsetConflict { declared, detected })
+    } else {
+        None
+    })
+}
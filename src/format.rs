@@ -0,0 +1,121 @@
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    encode_with_charset, encoding::decode_with_charset, normalize_whitespace_only,
+    parse_with_new_handle, EncodeError, ParseError,
+};
+
+/// Errors returned by [`format_file_in_place`]
+#[derive(Debug)]
+pub enum FormatError {
+    /// Parsing the EditorConfig files failed
+    Parse(ParseError),
+    /// Reading or writing the file failed
+    Io(io::Error),
+    /// The file content isn't valid in its `charset`
+    Decode,
+    /// The formatted content can't be encoded in its `charset`
+    Encode(EncodeError),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Parse(err) => write!(f, "{}", err),
+            FormatError::Io(err) => write!(f, "Failed to format file: {}", err),
+            FormatError::Decode => write!(f, "File content doesn't match its charset"),
+            FormatError::Encode(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FormatError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for FormatError {
+    fn from(err: ParseError) -> Self {
+        FormatError::Parse(err)
+    }
+}
+
+impl From<io::Error> for FormatError {
+    fn from(err: io::Error) -> Self {
+        FormatError::Io(err)
+    }
+}
+
+impl From<EncodeError> for FormatError {
+    fn from(err: EncodeError) -> Self {
+        FormatError::Encode(err)
+    }
+}
+
+/// Applies the whitespace-related rules for an absolute path to the file in
+/// place and returns whether it changed
+///
+/// Reads the file in its `charset`, applies
+/// [`normalize_whitespace_only`] and writes the result back in the same
+/// `charset`, including the byte order mark `utf-8-bom`, `utf-16be` and
+/// `utf-16le` call for. Without a `charset`, the file is read as UTF-8 and a
+/// byte order mark is kept as it is.
+///
+/// The file is only written if its content changes. The new content is
+/// written to a temporary file next to it, which then replaces the file, so
+/// a crash never leaves a partially written file behind.
+///
+/// # Example
+///
+/// ```no_run
+/// let test_file_path = std::fs::canonicalize("src/main.rs").unwrap();
+/// if editorconfig_rs::format_file_in_place(&test_file_path).unwrap() {
+///     println!("Formatted {}", test_file_path.display());
+/// }
+/// ```
+///
+pub fn format_file_in_place<P: AsRef<Path>>(absolute_path: P) -> Result<bool, FormatError> {
+    let absolute_path = absolute_path.as_ref();
+    let rules = parse_with_new_handle(absolute_path, |_| {})?;
+    let charset = rules.charset();
+
+    let bytes = fs::read(absolute_path)?;
+    let content = decode_with_charset(&bytes, charset).ok_or(FormatError::Decode)?;
+    let content = normalize_whitespace_only(&content, &rules);
+    let formatted = match charset {
+        Some(charset) => encode_with_charset(&content, charset)?,
+        None => content.into_bytes(),
+    };
+
+    if formatted == bytes {
+        return Ok(false);
+    }
+
+    write_atomically(absolute_path, &formatted)?;
+    Ok(true)
+}
+
+/// Writes `content` to a temporary file in the same directory as `path` and
+/// renames it to `path`, keeping the permissions of the original file
+fn write_atomically(path: &Path, content: &[u8]) -> io::Result<()> {
+    let mut temp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_file_name.push(".editorconfig-tmp");
+    let temp_path: PathBuf = path.with_file_name(temp_file_name);
+
+    let result = fs::write(&temp_path, content)
+        .and_then(|()| fs::File::open(&temp_path)?.sync_all())
+        .and_then(|()| fs::set_permissions(&temp_path, fs::metadata(path)?.permissions()))
+        .and_then(|()| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
@@ -0,0 +1,405 @@
+//! EditorConfig glob matching
+
+#[derive(Debug, Clone)]
+enum Token {
+    /// A literal character
+    Char(char),
+    /// `?` matches any single character except `/`
+    AnyChar,
+    /// `*` matches any string of characters except `/`
+    AnySeq,
+    /// `**` matches any string of characters
+    AnyPath,
+    /// `/**/` matches `/` or any string of characters enclosed by `/`
+    AnyDirs,
+    /// `[name]` or `[!name]` matches any single character (not) in `name`
+    Class(bool, Vec<(char, char)>),
+    /// `{s1,s2,s3}` matches any of the alternatives
+    Alternatives(Vec<Vec<Token>>),
+    /// `{num1..num2}` matches any integer between `num1` and `num2`
+    NumRange(i64, i64),
+}
+
+/// Returns whether an EditorConfig section glob matches a path
+///
+/// `path` is relative to the directory containing the `.editorconfig` file
+/// and uses `/` as separator; on Windows, `\` is accepted as separator as
+/// well. As with section names in a config file, a `pattern` without a `/`
+/// matches file names in any directory, while a `pattern` with a `/` is
+/// relative to the config file's directory.
+///
+/// Supported wildcards are `*`, `**`, `?`, `[name]`, `[!name]`,
+/// `{s1,s2,s3}` and `{num1..num2}`; special characters can be escaped with
+/// a backslash.
+///
+/// Matching takes time proportional to the length of `pattern` times the
+/// length of `path`, so globs from untrusted `.editorconfig` files can't make
+/// it hang.
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::glob_matches;
+///
+/// assert!(glob_matches("*.{js,ts}", "src/app.ts"));
+/// assert!(glob_matches("src/**.rs", "src/bin/main.rs"));
+/// assert!(!glob_matches("/*.rs", "src/lib.rs"));
+/// ```
+///
+#[must_use]
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    #[cfg(windows)]
+    let path = path.replace('\\', "/");
+
+    let pattern = if !pattern.contains('/') {
+        format!("/**/{}", pattern)
+    } else if pattern.starts_with('/') {
+        pattern.to_owned()
+    } else {
+        format!("/{}", pattern)
+    };
+
+    let path = if path.starts_with('/') {
+        path.to_owned()
+    } else {
+        format!("/{}", path)
+    };
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+
+    let mut program = Vec::new();
+    compile(&tokenize(&pattern), &mut program);
+    program.push(Inst::Match);
+    run(&program, &path)
+}
+
+/// Same as [`glob_matches`], but ignores case
+///
+/// EditorConfig globs are case-sensitive, but on case-insensitive file
+/// systems (the default on Windows and macOS) editors may expect a section
+/// like `[*.PNG]` to also match `image.png`.
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::{glob_matches, glob_matches_case_insensitive};
+///
+/// assert!(!glob_matches("*.PNG", "assets/image.png"));
+/// assert!(glob_matches_case_insensitive("*.PNG", "assets/image.png"));
+/// ```
+///
+#[must_use]
+pub fn glob_matches_case_insensitive(pattern: &str, path: &str) -> bool {
+    glob_matches(&pattern.to_lowercase(), &path.to_lowercase())
+}
+
+fn tokenize(pattern: &[char]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < pattern.len() {
+        match pattern[i] {
+            '\\' if i + 1 < pattern.len() => {
+                tokens.push(Token::Char(pattern[i + 1]));
+                i += 2;
+            }
+            '/' if pattern[i + 1..].starts_with(&['*', '*', '/']) => {
+                tokens.push(Token::AnyDirs);
+                i += 4;
+            }
+            '*' => {
+                let stars = pattern[i..].iter().take_while(|&&c| c == '*').count();
+                tokens.push(if stars > 1 {
+                    Token::AnyPath
+                } else {
+                    Token::AnySeq
+                });
+                i += stars;
+            }
+            '?' => {
+                tokens.push(Token::AnyChar);
+                i += 1;
+            }
+            '[' => match parse_class(&pattern[i + 1..]) {
+                Some((token, len)) => {
+                    tokens.push(token);
+                    i += len + 1;
+                }
+                None => {
+                    tokens.push(Token::Char('['));
+                    i += 1;
+                }
+            },
+            '{' => match parse_braces(&pattern[i + 1..]) {
+                Some((token, len)) => {
+                    tokens.push(token);
+                    i += len + 1;
+                }
+                None => {
+                    tokens.push(Token::Char('{'));
+                    i += 1;
+                }
+            },
+            c => {
+                tokens.push(Token::Char(c));
+                i += 1;
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Parses the contents of a `[...]` character class, starting after the `[`
+///
+/// Returns the token and the number of characters consumed including the
+/// closing `]`, or [`None`] if the class is unterminated or contains a `/`,
+/// in which case the `[` is matched literally.
+fn parse_class(pattern: &[char]) -> Option<(Token, usize)> {
+    let negated = pattern.first() == Some(&'!');
+    let mut i = usize::from(negated);
+    let mut ranges = Vec::new();
+
+    loop {
+        let c = match *pattern.get(i)? {
+            ']' if i > usize::from(negated) => return Some((Token::Class(negated, ranges), i + 1)),
+            '/' => return None,
+            '\\' if i + 1 < pattern.len() => {
+                i += 1;
+                pattern[i]
+            }
+            c => c,
+        };
+        i += 1;
+
+        match (pattern.get(i), pattern.get(i + 1)) {
+            (Some('-'), Some(&end)) if end != ']' && end != '/' => {
+                ranges.push((c, end));
+                i += 2;
+            }
+            _ => ranges.push((c, c)),
+        }
+    }
+}
+
+/// Parses the contents of a `{...}` brace expression, starting after the `{`
+///
+/// Returns the token and the number of characters consumed including the
+/// closing `}`, or [`None`] if the braces are unterminated or neither contain
+/// alternatives nor a numeric range, in which case the `{` is matched
+/// literally.
+fn parse_braces(pattern: &[char]) -> Option<(Token, usize)> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut alternatives = Vec::new();
+    let mut i = 0;
+
+    while i < pattern.len() {
+        match pattern[i] {
+            '\\' => i += 1,
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            ',' if depth == 0 => {
+                alternatives.push(&pattern[start..i]);
+                start = i + 1;
+            }
+            '}' => {
+                let len = i + 1;
+                if alternatives.is_empty() {
+                    let content: String = pattern[..i].iter().collect();
+                    return parse_num_range(&content).map(|token| (token, len));
+                }
+                alternatives.push(&pattern[start..i]);
+                let alternatives = alternatives.into_iter().map(tokenize).collect();
+                return Some((Token::Alternatives(alternatives), len));
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+fn parse_num_range(content: &str) -> Option<Token> {
+    let (start, end) = content.split_once("..")?;
+    let (start, end) = (parse_int(start)?, parse_int(end)?);
+    Some(Token::NumRange(start.min(end), start.max(end)))
+}
+
+fn parse_int(s: &str) -> Option<i64> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Instruction of a glob compiled to a nondeterministic automaton, which is
+/// run on all paths through it at once instead of backtracking
+#[derive(Debug)]
+enum Inst {
+    /// Consumes the given character
+    Char(char),
+    /// Consumes any character except `/`
+    AnyChar,
+    /// Consumes any character
+    Any,
+    /// Consumes a character (not) in the ranges
+    Class(bool, Vec<(char, char)>),
+    /// Consumes an integer between the bounds
+    NumRange(i64, i64),
+    /// Continues at all of the instructions without consuming anything
+    Split(Vec<usize>),
+    /// Continues at the instruction without consuming anything
+    Jump(usize),
+    /// Matches if the whole path was consumed
+    Match,
+}
+
+fn compile(tokens: &[Token], program: &mut Vec<Inst>) {
+    for token in tokens {
+        match token {
+            Token::Char(c) => program.push(Inst::Char(*c)),
+            Token::AnyChar => program.push(Inst::AnyChar),
+            Token::AnySeq => compile_repeat(Inst::AnyChar, program),
+            Token::AnyPath => compile_repeat(Inst::Any, program),
+            Token::AnyDirs => {
+                // `/`, optionally followed by any characters and another `/`
+                program.push(Inst::Char('/'));
+                let split = program.len();
+                program.push(Inst::Split(Vec::new()));
+                compile_repeat(Inst::Any, program);
+                program.push(Inst::Char('/'));
+                program[split] = Inst::Split(vec![split + 1, program.len()]);
+            }
+            Token::Class(negated, ranges) => program.push(Inst::Class(*negated, ranges.clone())),
+            Token::Alternatives(alternatives) => {
+                let split = program.len();
+                program.push(Inst::Split(Vec::new()));
+
+                let mut starts = Vec::new();
+                let mut jumps = Vec::new();
+                for alternative in alternatives {
+                    starts.push(program.len());
+                    compile(alternative, program);
+                    jumps.push(program.len());
+                    program.push(Inst::Jump(0));
+                }
+
+                let end = program.len();
+                for jump in jumps {
+                    program[jump] = Inst::Jump(end);
+                }
+                program[split] = Inst::Split(starts);
+            }
+            Token::NumRange(start, end) => program.push(Inst::NumRange(*start, *end)),
+        }
+    }
+}
+
+/// Compiles zero or more repetitions of a consuming instruction
+fn compile_repeat(inst: Inst, program: &mut Vec<Inst>) {
+    let split = program.len();
+    program.push(Inst::Split(vec![split + 1, split + 3]));
+    program.push(inst);
+    program.push(Inst::Jump(split));
+}
+
+fn run(program: &[Inst], path: &[char]) -> bool {
+    // Instructions to continue at for each position in the path; `NumRange`
+    // can consume several characters at once
+    let mut pending = vec![Vec::new(); path.len() + 1];
+    pending[0].push(0);
+
+    let digit_runs = digit_runs(path);
+    let mut visited = vec![usize::MAX; program.len()];
+    let mut stack = Vec::new();
+
+    for pos in 0..=path.len() {
+        stack.append(&mut pending[pos]);
+
+        while let Some(pc) = stack.pop() {
+            if visited[pc] == pos {
+                continue;
+            }
+            visited[pc] = pos;
+
+            let c = path.get(pos).copied();
+            let next = match &program[pc] {
+                Inst::Split(targets) => {
+                    stack.extend(targets.iter().rev());
+                    continue;
+                }
+                Inst::Jump(target) => {
+                    stack.push(*target);
+                    continue;
+                }
+                Inst::Match if pos == path.len() => return true,
+                Inst::Match => continue,
+                Inst::Char(expected) => (c == Some(*expected)).then(|| pos + 1),
+                Inst::AnyChar => c.filter(|&c| c != '/').map(|_| pos + 1),
+                Inst::Any => c.map(|_| pos + 1),
+                Inst::Class(negated, ranges) => c.filter(|&c| c != '/').and_then(|c| {
+                    let in_class = ranges.iter().any(|&(start, end)| start <= c && c <= end);
+                    (in_class != *negated).then(|| pos + 1)
+                }),
+                Inst::NumRange(start, end) => {
+                    num_len(path, &digit_runs, pos, *start, *end).map(|len| pos + len)
+                }
+            };
+
+            if let Some(next) = next {
+                pending[next].push(pc + 1);
+            }
+        }
+    }
+
+    false
+}
+
+/// Returns the number of consecutive ASCII digits and of consecutive zeros
+/// starting at each position
+fn digit_runs(path: &[char]) -> Vec<(usize, usize)> {
+    let mut runs = vec![(0, 0); path.len() + 1];
+    for pos in (0..path.len()).rev() {
+        let (digits, zeros) = runs[pos + 1];
+        runs[pos] = match path[pos] {
+            '0' => (digits + 1, zeros + 1),
+            c if c.is_ascii_digit() => (digits + 1, 0),
+            _ => (0, 0),
+        };
+    }
+    runs
+}
+
+/// Returns the length of the integer at `pos` if it is between `start` and
+/// `end`
+fn num_len(
+    path: &[char],
+    digit_runs: &[(usize, usize)],
+    pos: usize,
+    start: i64,
+    end: i64,
+) -> Option<usize> {
+    let sign_len = usize::from(path.get(pos) == Some(&'-'));
+    let (digits_len, zeros_len) = digit_runs[pos + sign_len];
+    if digits_len == 0 {
+        return None;
+    }
+
+    // Leading zeros don't change the value, and anything longer than 19
+    // digits is out of range for an `i64` anyway
+    let significant_len = digits_len - zeros_len;
+    if significant_len > 19 {
+        return None;
+    }
+
+    let len = sign_len + digits_len;
+    let mut num: String = path[pos..pos + sign_len].iter().collect();
+    num.push('0');
+    num.extend(&path[pos + len - significant_len..pos + len]);
+    let num = num.parse::<i64>().ok()?;
+    (start <= num && num <= end).then(|| len)
+}
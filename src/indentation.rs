@@ -0,0 +1,40 @@
+use crate::Rules;
+
+/// Returns the line numbers, starting at 1, whose leading whitespace mixes
+/// tabs and spaces
+///
+/// Only reports lines if `rules` specify an `indent_style`, since mixing is
+/// only unintended if a single style is expected. Lines are split at `\n`.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use editorconfig_rs::Rules;
+///
+/// let mut rules = HashMap::new();
+/// rules.insert("indent_style".to_owned(), "space".to_owned());
+/// let rules = Rules::from(rules);
+///
+/// let content = "fn main() {\n    let a = 1;\n  \tlet b = 2;\n}\n";
+/// let lines = editorconfig_rs::find_mixed_indentation(content, &rules);
+/// assert_eq!(lines, vec![3]);
+/// ```
+///
+#[must_use]
+pub fn find_mixed_indentation(content: &str, rules: &Rules) -> Vec<usize> {
+    if rules.indent_style().is_none() {
+        return Vec::new();
+    }
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let content = line.trim_start_matches(|c| c == ' ' || c == '\t');
+            let indentation = &line[..line.len() - content.len()];
+            indentation.contains(' ') && indentation.contains('\t')
+        })
+        .map(|(line_index, _)| line_index + 1)
+        .collect()
+}
@@ -2,11 +2,16 @@
 #![deny(missing_docs)]
 
 use std::{
-    collections::HashMap,
+    cell::{Cell, RefCell},
+    collections::{BTreeSet, HashMap},
+    error::Error,
     ffi::{CStr, CString},
+    fmt, fs, io, mem,
     os::raw::{c_int, c_void},
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     ptr,
+    str::{FromStr, Utf8Error},
+    time::{Duration, Instant},
 };
 
 use editorconfig_sys::{
@@ -14,10 +19,41 @@ use editorconfig_sys::{
     EDITORCONFIG_PARSE_VERSION_TOO_NEW,
 };
 
+mod builder;
+mod classify;
+mod config_files;
+mod document;
+mod encoding;
+mod format;
+mod glob;
+mod indentation;
+mod line_endings;
+pub mod properties;
+mod rules;
+mod whitespace;
+
+pub use builder::{EditorConfigHandleBuilder, HandleError};
+pub use classify::{Indentation, PathClass};
+pub use config_files::{all_sections, find_nearest_config, has_any_config, SectionInfo};
+pub use document::{
+    parse_config_document, ConfigDocument, ConfigProperty, ConfigSection, LineKind,
+};
+pub use encoding::{charset_conflict, encode_with_charset, CharsetConflict, EncodeError};
+pub use format::{format_file_in_place, FormatError};
+pub use glob::{glob_matches, glob_matches_case_insensitive};
+pub use indentation::find_mixed_indentation;
+pub use line_endings::{line_ending_report, normalize_line_endings, LineEndingReport};
+pub use rules::{Charset, EndOfLine, IndentStyle, PropertyError, Rules};
+pub use whitespace::{normalize_reader, normalize_whitespace_only};
+
 /// EditorConfig handle
 pub struct EditorConfigHandle {
     handle: *mut c_void,
     config_filename: Option<CString>,
+    last_error_code: Cell<Option<c_int>>,
+    configured_version: Cell<Option<Version<c_int>>>,
+    parsed_path: RefCell<Option<PathBuf>>,
+    stopped_at_root: Cell<Option<bool>>,
 }
 
 /// EditorConfig version
@@ -33,6 +69,7 @@ pub struct Version<T: Into<c_int>> {
 
 impl<T: Into<c_int> + Copy> Version<T> {
     /// Safe [`Version`] constructor that panics when negative numbers are used
+    #[must_use]
     pub fn new(major: T, minor: T, patch: T) -> Self {
         if c_int::is_negative(major.into())
             || c_int::is_negative(minor.into())
@@ -49,6 +86,152 @@ impl<T: Into<c_int> + Copy> Version<T> {
     }
 }
 
+impl Version<c_int> {
+    /// Version `0.0.0`, which is also the version of a new
+    /// [`EditorConfigHandle`] whose version wasn't set
+    pub const ZERO: Version<c_int> = Version {
+        major: 0,
+        minor: 0,
+        patch: 0,
+    };
+
+    /// Creates a [`Version`] from `u64` version numbers, e.g. parsed from JSON
+    /// or command line arguments
+    ///
+    /// Returns a [`VersionError`] if a version number doesn't fit into a
+    /// [`c_int`] instead of truncating it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::Version;
+    ///
+    /// let version = Version::from_u64_components(0, 12, 5);
+    /// # assert_eq!(version, Ok(Version::new(0, 12, 5)));
+    /// let err = Version::from_u64_components(0, u64::MAX, 5).unwrap_err();
+    /// # assert_eq!(err.component, "minor");
+    /// ```
+    ///
+    pub fn from_u64_components(major: u64, minor: u64, patch: u64) -> Result<Self, VersionError> {
+        let component = |component, value| {
+            c_int::try_from(value).map_err(|_| VersionError { component, value })
+        };
+
+        Ok(Version::new(
+            component("major", major)?,
+            component("minor", minor)?,
+            component("patch", patch)?,
+        ))
+    }
+
+    /// Returns `true` if both versions have the same major and minor version,
+    /// regardless of their patch versions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::Version;
+    ///
+    /// assert!(Version::new(0, 12, 5).matches_minor(&Version::new(0, 12, 0)));
+    /// assert!(!Version::new(0, 12, 5).matches_minor(&Version::new(0, 11, 5)));
+    /// ```
+    ///
+    #[must_use]
+    pub fn matches_minor(&self, other: &Version<c_int>) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+
+    /// Returns a copy of this version with the patch version set to `0`
+    ///
+    /// Useful for "at least major.minor" checks that should ignore the patch
+    /// version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::Version;
+    ///
+    /// let version = editorconfig_rs::get_version();
+    /// if version.without_patch() >= Version::new(0, 12, 0) {
+    ///     println!("libeditorconfig {}.{} or newer", version.major, version.minor);
+    /// }
+    /// # assert_eq!(Version::new(0, 12, 5).without_patch(), Version::new(0, 12, 0));
+    /// ```
+    ///
+    #[must_use]
+    pub fn without_patch(&self) -> Version<c_int> {
+        Version { patch: 0, ..*self }
+    }
+
+    /// Returns the next minor version with the patch version reset to `0`
+    ///
+    /// Returns the version unchanged if the minor version is already
+    /// [`c_int::MAX`], since resetting only the patch version would return a
+    /// lower version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::Version;
+    ///
+    /// assert_eq!(Version::new(0, 12, 5).next_minor(), Version::new(0, 13, 0));
+    /// ```
+    ///
+    #[must_use]
+    pub fn next_minor(&self) -> Version<c_int> {
+        match self.minor.checked_add(1) {
+            Some(minor) => Version {
+                minor,
+                patch: 0,
+                ..*self
+            },
+            None => *self,
+        }
+    }
+
+    /// Returns the next patch version
+    ///
+    /// The patch version saturates at [`c_int::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::Version;
+    ///
+    /// assert_eq!(Version::new(0, 12, 5).next_patch(), Version::new(0, 12, 6));
+    /// ```
+    ///
+    #[must_use]
+    pub fn next_patch(&self) -> Version<c_int> {
+        Version {
+            patch: self.patch.saturating_add(1),
+            ..*self
+        }
+    }
+}
+
+/// Error returned by [`Version::from_u64_components`] when a version number
+/// doesn't fit into a [`c_int`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionError {
+    /// Name of the version number, `major`, `minor` or `patch`
+    pub component: &'static str,
+    /// The out-of-range value
+    pub value: u64,
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid {} version number: {}",
+            self.component, self.value
+        )
+    }
+}
+
+impl Error for VersionError {}
+
 /// Parsing errors returned by [`EditorConfigHandle::parse`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseError {
@@ -62,9 +245,130 @@ pub enum ParseError {
     /// [`EditorConfigHandle::parse`] returns this error if your config file is
     /// invalid including the line number where the error occured
     LineError(c_int),
+    /// [`EditorConfigHandle::parse_dir_for`] returns this error if the
+    /// filename isn't a single file name
+    InvalidFilenameError,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::LineError(line_num) => {
+                write!(f, "Failed to parse config file at line {}", line_num)
+            }
+            ParseError::InvalidFilenameError => {
+                write!(f, "Filename must be a single path component")
+            }
+            _ => match get_error_message(*self) {
+                Some(err_msg) => write!(f, "{}", err_msg),
+                None => write!(f, "{:?}", self),
+            },
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Errors returned by [`EditorConfigHandle::parse_relative_to`]
+///
+/// Keeps failures to access or canonicalize a path apart from errors while
+/// parsing the EditorConfig files themselves.
+#[derive(Debug)]
+pub enum ResolveError {
+    /// The path couldn't be canonicalized, e.g. because it doesn't exist
+    Io(io::Error),
+    /// The path was canonicalized, but parsing failed
+    Parse(ParseError),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::Io(err) => write!(f, "Failed to resolve path: {}", err),
+            ResolveError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ResolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ResolveError::Io(err) => Some(err),
+            ResolveError::Parse(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ResolveError {
+    fn from(err: io::Error) -> Self {
+        ResolveError::Io(err)
+    }
+}
+
+impl From<ParseError> for ResolveError {
+    fn from(err: ParseError) -> Self {
+        ResolveError::Parse(err)
+    }
+}
+
+/// Errors returned by [`rules_as_env`] and [`rules_as_shell_exports`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    /// Parsing the path failed
+    Parse(ParseError),
+    /// Several rule names map to the same environment variable name, e.g.
+    /// `foo-bar` and `foo.bar` both map to `EDITORCONFIG_FOO_BAR`
+    NameCollision {
+        /// The environment variable name
+        name: String,
+        /// The colliding rule names, sorted
+        rule_names: Vec<String>,
+    },
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvError::Parse(err) => write!(f, "{}", err),
+            EnvError::NameCollision { name, rule_names } => write!(
+                f,
+                "Rules {} all map to environment variable {}",
+                rule_names.join(", "),
+                name
+            ),
+        }
+    }
+}
+
+impl Error for EnvError {}
+
+impl From<ParseError> for EnvError {
+    fn from(err: ParseError) -> Self {
+        EnvError::Parse(err)
+    }
 }
 
 impl EditorConfigHandle {
+    /// Returns an [`EditorConfigHandleBuilder`] to create a configured
+    /// [`EditorConfigHandle`] without panicking on invalid input
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::{EditorConfigHandle, Version};
+    ///
+    /// let handle = EditorConfigHandle::builder()
+    ///     .config_filename(".myeditorconfig")
+    ///     .version(Version::new(0, 12, 5))
+    ///     .build();
+    /// # assert!(handle.is_ok());
+    /// ```
+    ///
+    #[must_use]
+    pub fn builder() -> EditorConfigHandleBuilder {
+        EditorConfigHandleBuilder::default()
+    }
+
     /// Creates a new [`EditorConfigHandle`]
     ///
     /// # Example
@@ -82,6 +386,10 @@ impl EditorConfigHandle {
             Ok(EditorConfigHandle {
                 handle,
                 config_filename: None,
+                last_error_code: Cell::new(None),
+                configured_version: Cell::new(None),
+                parsed_path: RefCell::new(None),
+                stopped_at_root: Cell::new(None),
             })
         }
     }
@@ -94,9 +402,10 @@ impl EditorConfigHandle {
     /// # use editorconfig_rs::Version;
     /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
     /// let version = handle.get_version();
-    /// # assert_eq!(version, Version::new(0, 0, 0));
+    /// # assert_eq!(version, Version::ZERO);
     /// ```
     ///
+    #[must_use]
     pub fn get_version(&self) -> Version<c_int> {
         let (mut major, mut minor, mut patch) = (-1, -1, -1);
 
@@ -112,7 +421,12 @@ impl EditorConfigHandle {
         Version::new(major, minor, patch)
     }
 
-    /// TODO: Add comment
+    /// Sets the EditorConfig [version](Version) used when parsing
+    ///
+    /// Version numbers are converted with [`Into<c_int>`], which is only
+    /// implemented for types that convert losslessly. Wider types like `u64`
+    /// don't compile and must be converted with [`TryFrom`] first, so a
+    /// version number is never truncated silently.
     ///
     /// # Example
     ///
@@ -123,15 +437,53 @@ impl EditorConfigHandle {
     /// handle.set_version(Version::new(0, 12, 5));
     /// ```
     ///
+    /// ```compile_fail
+    /// use editorconfig_rs::Version;
+    ///
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// handle.set_version(Version::new(0u64, 12, 5));
+    /// ```
+    ///
     pub fn set_version<T: Into<c_int>>(&self, version: Version<T>) {
+        let version = Version {
+            major: version.major.into(),
+            minor: version.minor.into(),
+            patch: version.patch.into(),
+        };
+
         unsafe {
             editorconfig_sys::editorconfig_handle_set_version(
                 self.handle,
-                version.major.into(),
-                version.minor.into(),
-                version.patch.into(),
+                version.major,
+                version.minor,
+                version.patch,
             );
         };
+
+        self.configured_version.set(Some(version));
+    }
+
+    /// Returns the [version](Version) last set with
+    /// [`EditorConfigHandle::set_version`] or [`None`] if it was never set
+    ///
+    /// Unlike [`EditorConfigHandle::get_version`], this doesn't query
+    /// `libeditorconfig`, but returns the version as requested by the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use editorconfig_rs::Version;
+    ///
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// # assert!(handle.configured_version().is_none());
+    /// handle.set_version(Version::new(0, 12, 5));
+    /// let version = handle.configured_version();
+    /// # assert_eq!(version, Some(Version::new(0, 12, 5)));
+    /// ```
+    ///
+    #[must_use]
+    pub fn configured_version(&self) -> Option<Version<c_int>> {
+        self.configured_version.get()
     }
 
     /// Returns the configuration filename iff it was previously set by calling
@@ -139,15 +491,31 @@ impl EditorConfigHandle {
     ///
     /// Note: [`None`] just means the default filename `".editorconfig"` is used
     ///
+    #[must_use]
     pub fn get_config_filename(&self) -> Option<String> {
+        self.try_get_config_filename().ok().flatten()
+    }
+
+    /// Same as [`EditorConfigHandle::get_config_filename`], but returns an
+    /// error if the configuration filename isn't valid UTF-8 instead of
+    /// [`None`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let config_filename = handle.try_get_config_filename();
+    /// # assert_eq!(config_filename, Ok(None));
+    /// ```
+    ///
+    pub fn try_get_config_filename(&self) -> Result<Option<String>, Utf8Error> {
         let filename =
             unsafe { editorconfig_sys::editorconfig_handle_get_conf_file_name(self.handle) };
         if filename.is_null() {
-            None
+            Ok(None)
         } else {
             let filename = unsafe { CStr::from_ptr(filename) };
-            let filename = filename.to_str().map(|s| s.to_owned());
-            filename.ok()
+            filename.to_str().map(|s| Some(s.to_owned()))
         }
     }
 
@@ -164,7 +532,12 @@ impl EditorConfigHandle {
     ///
     pub fn set_config_filename(&mut self, filename: &str) {
         let err_msg = format!("Failed to create CString from filename: {}", filename);
-        let filename = CString::new(filename).expect(&err_msg);
+        self.try_set_config_filename(filename).expect(&err_msg);
+    }
+
+    fn try_set_config_filename(&mut self, filename: &str) -> Result<(), HandleError> {
+        let filename = CString::new(filename)
+            .map_err(|_| HandleError::InvalidConfigFilename(filename.to_owned()))?;
         unsafe {
             editorconfig_sys::editorconfig_handle_set_conf_file_name(
                 self.handle,
@@ -174,6 +547,27 @@ impl EditorConfigHandle {
 
         // Store the CString so it lives as long as the handle
         self.config_filename = Some(filename);
+        Ok(())
+    }
+
+    /// Resets the configuration filename to the default `".editorconfig"`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// handle.set_config_filename(".myeditorconfig");
+    /// handle.clear_config_filename();
+    /// # assert!(handle.get_config_filename().is_none());
+    /// ```
+    ///
+    pub fn clear_config_filename(&mut self) {
+        unsafe {
+            editorconfig_sys::editorconfig_handle_set_conf_file_name(self.handle, ptr::null());
+        };
+
+        // The handle no longer points to the CString, so it can be dropped
+        self.config_filename = None;
     }
 
     /// Searches an absolute path for the corresponding EditorConfig rules
@@ -181,6 +575,12 @@ impl EditorConfigHandle {
     /// After parsing, you can get the rules by calling
     /// [`EditorConfigHandle::get_rules`].
     ///
+    /// The path doesn't need to exist. A directory path is treated like a file
+    /// with the directory's name: section globs are matched against the path
+    /// itself and configuration files are searched in its parent directories.
+    /// To get the rules for a file inside a directory, use
+    /// [`EditorConfigHandle::parse_dir_for`].
+    ///
     /// # Example
     ///
     /// ```
@@ -190,13 +590,20 @@ impl EditorConfigHandle {
     /// # assert!(err.is_none());
     /// ```
     ///
+    #[must_use = "parse errors should be handled before getting the rules"]
     pub fn parse<P: AsRef<Path>>(&self, absolute_path: P) -> Option<ParseError> {
+        self.parsed_path
+            .replace(Some(absolute_path.as_ref().to_path_buf()));
+        self.stopped_at_root.set(None);
+
         let absolute_path = absolute_path.as_ref().to_str().expect("Invalid UTF-8 path");
         let err_msg = format!("Failed to create CString from path: {}", absolute_path);
         let absolute_path = CString::new(absolute_path).expect(&err_msg);
 
         let err_num =
             unsafe { editorconfig_sys::editorconfig_parse(absolute_path.as_ptr(), self.handle) };
+        self.last_error_code.set(Some(err_num));
+
         match err_num {
             0 => None,
             EDITORCONFIG_PARSE_VERSION_TOO_NEW => Some(ParseError::VersionTooNewError),
@@ -207,6 +614,184 @@ impl EditorConfigHandle {
         }
     }
 
+    /// Searches the EditorConfig rules for a file named `filename` in the
+    /// absolute directory path `dir`
+    ///
+    /// The file doesn't need to exist, so this answers "which rules would
+    /// apply to `filename` in this directory?", e.g. before creating the file.
+    ///
+    /// Returns [`ParseError::InvalidFilenameError`] without parsing if
+    /// `filename` isn't a single file name, e.g. if it's empty, absolute, `..`
+    /// or includes a directory like `../file.rs`, since the rules would then
+    /// be searched for a file outside of `dir`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_dir_path = std::fs::canonicalize("tests").unwrap();
+    /// let err = handle.parse_dir_for(test_dir_path, "new_file.rs");
+    /// # assert!(err.is_none());
+    /// # assert_eq!(handle.get_rules().get("insert_final_newline").unwrap(), "true");
+    /// ```
+    ///
+    #[must_use = "parse errors should be handled before getting the rules"]
+    pub fn parse_dir_for<P: AsRef<Path>>(&self, dir: P, filename: &str) -> Option<ParseError> {
+        let mut components = Path::new(filename).components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return Some(ParseError::InvalidFilenameError);
+        }
+
+        self.parse(dir.as_ref().join(filename))
+    }
+
+    /// Resolves `target` against `base`, canonicalizes the resulting path and
+    /// parses it
+    ///
+    /// This saves build tools, whose paths are often relative to a manifest
+    /// directory rather than the current directory, from joining and
+    /// canonicalizing paths themselves. Because the path is canonicalized,
+    /// it must exist; otherwise [`ResolveError::Io`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let result = handle.parse_relative_to("tests", "editorconfig.rs");
+    /// # assert!(result.is_ok());
+    /// ```
+    ///
+    pub fn parse_relative_to<B: AsRef<Path>, T: AsRef<Path>>(
+        &self,
+        base: B,
+        target: T,
+    ) -> Result<(), ResolveError> {
+        let absolute_path = fs::canonicalize(base.as_ref().join(target))?;
+        match self.parse(absolute_path) {
+            Some(err) => Err(ResolveError::Parse(err)),
+            None => Ok(()),
+        }
+    }
+
+    /// Same as [`EditorConfigHandle::parse`], but also returns how long parsing
+    /// took
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+    /// let (err, duration) = handle.parse_timed(test_file_path);
+    /// # assert!(err.is_none());
+    /// println!("Parsing took {:?}", duration);
+    /// ```
+    ///
+    #[must_use = "parse errors should be handled before getting the rules"]
+    pub fn parse_timed<P: AsRef<Path>>(&self, absolute_path: P) -> (Option<ParseError>, Duration) {
+        let start = Instant::now();
+        let err = self.parse(absolute_path);
+        (err, start.elapsed())
+    }
+
+    /// Returns the raw error code `libeditorconfig` returned from the last call
+    /// to [`EditorConfigHandle::parse`] or [`None`] if nothing was parsed yet
+    ///
+    /// The error code is `0` if parsing succeeded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// # assert!(handle.last_error_code().is_none());
+    /// let err = handle.parse("relative/path");
+    /// # assert!(err.is_some());
+    /// if let Some(err_code) = handle.last_error_code() {
+    ///     println!("libeditorconfig returned {}", err_code);
+    /// }
+    /// ```
+    ///
+    #[must_use]
+    pub fn last_error_code(&self) -> Option<c_int> {
+        self.last_error_code.get()
+    }
+
+    /// Returns `true` if the configuration files found for the path last
+    /// passed to [`EditorConfigHandle::parse`] include one with `root = true`
+    ///
+    /// In that case, `libeditorconfig` didn't consult any configuration files
+    /// above the root file. Returns `false` if nothing was parsed yet or the
+    /// last parse returned an error.
+    ///
+    /// `libeditorconfig` doesn't report which files it read, so the first call
+    /// after a successful parse scans the configuration files again and
+    /// caches the result until the next parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_file_path = std::fs::canonicalize("tests/editorconfig.rs").unwrap();
+    /// let err = handle.parse(test_file_path);
+    /// # assert!(err.is_none());
+    /// if handle.stopped_at_root() {
+    ///     println!("No configuration files above the root file were used");
+    /// }
+    /// # assert!(handle.stopped_at_root());
+    /// ```
+    ///
+    #[must_use]
+    pub fn stopped_at_root(&self) -> bool {
+        if self.last_error_code.get() != Some(0) {
+            return false;
+        }
+        if let Some(stopped_at_root) = self.stopped_at_root.get() {
+            return stopped_at_root;
+        }
+
+        let parsed_path = self.parsed_path.borrow();
+        let absolute_path = match parsed_path.as_deref() {
+            Some(path) => path,
+            None => return false,
+        };
+        let config_filename = self
+            .config_filename
+            .as_ref()
+            .and_then(|filename| filename.to_str().ok());
+        let stopped_at_root = config_files::stops_at_root(absolute_path, config_filename);
+        self.stopped_at_root.set(Some(stopped_at_root));
+        stopped_at_root
+    }
+
+    /// Returns the directory containing the path last passed to
+    /// [`EditorConfigHandle::parse`], where the search for configuration
+    /// files started
+    ///
+    /// Returns [`None`] if nothing was parsed yet or the path wasn't absolute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_file_path = std::fs::canonicalize("tests/editorconfig.rs").unwrap();
+    /// let err = handle.parse(test_file_path);
+    /// # assert!(err.is_none());
+    /// let target_dir = handle.target_directory();
+    /// # assert_eq!(target_dir, Some(std::fs::canonicalize("tests").unwrap()));
+    /// ```
+    ///
+    #[must_use]
+    pub fn target_directory(&self) -> Option<PathBuf> {
+        let parsed_path = self.parsed_path.borrow();
+        parsed_path
+            .as_deref()
+            .filter(|path| path.is_absolute())?
+            .parent()
+            .map(Path::to_path_buf)
+    }
+
     /// Returns the [path](PathBuf) of the invalid configuration file when
     /// [parse](EditorConfigHandle::parse) returned an [error](ParseError)
     ///
@@ -215,15 +800,61 @@ impl EditorConfigHandle {
     /// The [path](PathBuf) of the invalid configuration file or [`None`] if
     /// there was no error
     ///
+    #[must_use]
     pub fn get_error_file(&self) -> Option<PathBuf> {
+        self.try_get_error_file().ok().flatten()
+    }
+
+    /// Same as [`EditorConfigHandle::get_error_file`], but returns an error if
+    /// the path of the invalid configuration file isn't valid UTF-8 instead of
+    /// [`None`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+    /// let err = handle.parse(test_file_path);
+    /// # assert!(err.is_none());
+    /// let err_file_path = handle.try_get_error_file();
+    /// # assert_eq!(err_file_path, Ok(None));
+    /// ```
+    ///
+    pub fn try_get_error_file(&self) -> Result<Option<PathBuf>, Utf8Error> {
         let err_file_path =
             unsafe { editorconfig_sys::editorconfig_handle_get_err_file(self.handle) };
         if err_file_path.is_null() {
-            None
+            Ok(None)
         } else {
             let err_file_path = unsafe { CStr::from_ptr(err_file_path) };
-            err_file_path.to_str().map(PathBuf::from).ok()
+            err_file_path.to_str().map(|s| Some(PathBuf::from(s)))
+        }
+    }
+
+    /// Consumes the [`EditorConfigHandle`] and returns the raw
+    /// `libeditorconfig` handle
+    ///
+    /// The caller becomes responsible for destroying the raw handle with
+    /// `editorconfig_handle_destroy`. A configuration filename set with
+    /// [`EditorConfigHandle::set_config_filename`] is leaked, because the raw
+    /// handle keeps pointing to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let raw_handle = handle.into_raw();
+    /// unsafe { editorconfig_sys::editorconfig_handle_destroy(raw_handle) };
+    /// ```
+    ///
+    #[must_use = "the raw handle must be destroyed with `editorconfig_handle_destroy`"]
+    pub fn into_raw(mut self) -> *mut c_void {
+        if let Some(filename) = self.config_filename.take() {
+            let _ = filename.into_raw();
         }
+
+        // Null the handle so `Drop` doesn't destroy it
+        mem::replace(&mut self.handle, ptr::null_mut())
     }
 
     /// Returns the number of rules found after parsing
@@ -237,11 +868,14 @@ impl EditorConfigHandle {
     /// # assert_eq!(rule_count, 0);
     /// ```
     ///
+    #[must_use]
     pub fn get_rule_count(&self) -> c_int {
         unsafe { editorconfig_sys::editorconfig_handle_get_name_value_count(self.handle) }
     }
 
-    /// Returns a map of all rules found after parsing
+    /// Returns all [rules](Rules) found after parsing
+    ///
+    /// `Rules::from(&handle)` and `(&handle).into()` do the same.
     ///
     /// # Example
     ///
@@ -254,9 +888,87 @@ impl EditorConfigHandle {
     /// # assert_eq!(rules.len(), 2);
     /// ```
     ///
-    pub fn get_rules(&self) -> HashMap<String, String> {
+    #[must_use]
+    pub fn get_rules(&self) -> Rules {
+        let mut rules = HashMap::new();
+        self.get_rules_into(&mut rules);
+        Rules::from(rules)
+    }
+
+    /// Returns the [rules](Rules) found after parsing for the
+    /// [known properties](properties::KNOWN_PROPERTIES) only, without any
+    /// custom properties
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_dir_path = std::fs::canonicalize("tests/rules").unwrap();
+    /// let err = handle.parse_dir_for(test_dir_path, "file.custom");
+    /// # assert!(err.is_none());
+    /// let rules = handle.get_standard_rules();
+    /// # assert!(rules.contains_key("indent_style"));
+    /// # assert!(!rules.contains_key("custom_property"));
+    /// ```
+    ///
+    #[must_use]
+    pub fn get_standard_rules(&self) -> Rules {
+        self.get_rules()
+            .into_iter()
+            .filter(|(name, _)| properties::KNOWN_PROPERTIES.contains(&name.as_str()))
+            .collect::<HashMap<_, _>>()
+            .into()
+    }
+
+    /// Returns the value of the rule `name` parsed into `T`, or `default` if
+    /// the rule isn't set or its value can't be parsed
+    ///
+    /// This is useful for custom properties whose type is known to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+    /// let err = handle.parse(test_file_path);
+    /// # assert!(err.is_none());
+    /// let margin = handle.get_or("my_custom_margin", 4u32);
+    /// # assert_eq!(margin, 4);
+    /// ```
+    ///
+    #[must_use]
+    pub fn get_or<T: FromStr>(&self, name: &str, default: T) -> T {
+        self.get_rules()
+            .get(name)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    }
+
+    /// Clears `rules` and fills it with all rules found after parsing
+    ///
+    /// Unlike [`EditorConfigHandle::get_rules`], this reuses the allocations
+    /// of an existing map, which helps when parsing many files in a row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let mut rules = HashMap::new();
+    /// for path in ["tests/🦀🚀", "tests/editorconfig.rs"] {
+    ///     let test_file_path = std::fs::canonicalize(path).unwrap();
+    ///     let err = handle.parse(test_file_path);
+    /// #   assert!(err.is_none());
+    ///     handle.get_rules_into(&mut rules);
+    /// }
+    /// # assert_eq!(rules.len(), 4);
+    /// ```
+    ///
+    pub fn get_rules_into(&self, rules: &mut HashMap<String, String>) {
         let rule_count = self.get_rule_count();
-        let mut rules = HashMap::with_capacity(rule_count as usize);
+        rules.clear();
+        rules.reserve(rule_count as usize);
 
         for rule_index in 0..rule_count {
             let (mut rule_name, mut rule_value) = (ptr::null(), ptr::null());
@@ -285,22 +997,28 @@ impl EditorConfigHandle {
                 rules.insert(rule_name, rule_value);
             }
         }
-
-        rules
     }
 }
 
 impl Drop for EditorConfigHandle {
     fn drop(&mut self) {
+        if self.handle.is_null() {
+            return;
+        }
+
         unsafe {
             editorconfig_sys::editorconfig_handle_destroy(self.handle);
         }
+        self.handle = ptr::null_mut();
     }
 }
 
 /// Gets the error message for a [parsing error](ParseError) from the
 /// underlying `libeditorconfig` C library
 ///
+/// Returns [`None`] for [`ParseError::InvalidFilenameError`], which
+/// `libeditorconfig` doesn't know about.
+///
 /// # Example
 ///
 /// ```
@@ -313,12 +1031,14 @@ impl Drop for EditorConfigHandle {
 /// # else { panic!(); }
 /// ```
 ///
+#[must_use]
 pub fn get_error_message(parse_error: ParseError) -> Option<String> {
     let err_num = match parse_error {
         ParseError::VersionTooNewError => EDITORCONFIG_PARSE_VERSION_TOO_NEW,
         ParseError::MemoryError => EDITORCONFIG_PARSE_MEMORY_ERROR,
         ParseError::NotFullPathError => EDITORCONFIG_PARSE_NOT_FULL_PATH,
         ParseError::LineError(line_num) => line_num,
+        ParseError::InvalidFilenameError => return None,
     };
 
     let err_msg = unsafe { editorconfig_sys::editorconfig_get_error_msg(err_num) };
@@ -344,6 +1064,7 @@ pub fn get_error_message(parse_error: ParseError) -> Option<String> {
 /// # assert!(patch >= 5);
 /// ```
 ///
+#[must_use]
 pub fn get_version() -> Version<c_int> {
     let (mut major, mut minor, mut patch) = (-1, -1, -1);
     unsafe {
@@ -352,3 +1073,208 @@ pub fn get_version() -> Version<c_int> {
 
     Version::new(major, minor, patch)
 }
+
+/// Returns the highest of `versions` that doesn't exceed the
+/// [version](get_version) of the underlying `libeditorconfig` C library
+///
+/// Returns [`None`] if `versions` is empty or all of them are newer than the
+/// library, which would otherwise cause [`ParseError::VersionTooNewError`].
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::Version;
+///
+/// let candidates = [Version::new(0, 12, 0), Version::new(0, 12, 5), Version::new(99, 0, 0)];
+/// let version = editorconfig_rs::max_supported_version(&candidates);
+/// # assert_eq!(version, Some(Version::new(0, 12, 5)));
+/// if let Some(version) = version {
+///     let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+///     handle.set_version(version);
+/// }
+/// ```
+///
+#[must_use]
+pub fn max_supported_version(versions: &[Version<c_int>]) -> Option<Version<c_int>> {
+    let library_version = get_version();
+    versions
+        .iter()
+        .copied()
+        .filter(|version| *version <= library_version)
+        .max()
+}
+
+/// A rule name and its values under two different [versions](Version) as
+/// returned by [`resolve_diff`]
+///
+/// A value is [`None`] if the rule isn't set for that version.
+pub type RuleDiff = (String, Option<String>, Option<String>);
+
+/// Parses an absolute path once for each [version](Version) and returns all
+/// rules whose values differ, sorted by rule name
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::Version;
+///
+/// let test_file_path = std::fs::canonicalize("tests/rules").unwrap().join("file.tab");
+/// let diff = editorconfig_rs::resolve_diff(
+///     test_file_path,
+///     Version::new(0, 9, 0),
+///     Version::new(0, 10, 0),
+/// )
+/// .unwrap();
+/// for (name, value1, value2) in diff {
+///     println!("{}: {:?} -> {:?}", name, value1, value2);
+/// }
+/// ```
+///
+pub fn resolve_diff<P: AsRef<Path>, T: Into<c_int>>(
+    absolute_path: P,
+    version1: Version<T>,
+    version2: Version<T>,
+) -> Result<Vec<RuleDiff>, ParseError> {
+    let absolute_path = absolute_path.as_ref();
+    let rules1 = parse_with_new_handle(absolute_path, |handle| handle.set_version(version1))?;
+    let rules2 = parse_with_new_handle(absolute_path, |handle| handle.set_version(version2))?;
+
+    let rule_names: BTreeSet<&String> = rules1.keys().chain(rules2.keys()).collect();
+    let diff = rule_names
+        .into_iter()
+        .filter_map(|rule_name| {
+            let (value1, value2) = (rules1.get(rule_name), rules2.get(rule_name));
+            if value1 == value2 {
+                None
+            } else {
+                Some((
+                    rule_name.to_owned(),
+                    value1.map(|s| s.to_owned()),
+                    value2.map(|s| s.to_owned()),
+                ))
+            }
+        })
+        .collect();
+
+    Ok(diff)
+}
+
+/// Parses an absolute path and returns its rules as environment variables
+///
+/// Each rule name is uppercased, prefixed with `EDITORCONFIG_` and any
+/// character other than ASCII letters, digits and `_` is replaced with `_`.
+/// The variables are sorted by name. If several rule names map to the same
+/// variable name, [`EnvError::NameCollision`] is returned.
+///
+/// The values are returned verbatim and come from config files that may not
+/// be trusted. Quote them before passing them to a shell, or use
+/// [`rules_as_shell_exports`], which does that for you.
+///
+/// # Example
+///
+/// ```
+/// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+/// for (name, value) in editorconfig_rs::rules_as_env(test_file_path).unwrap() {
+///     std::env::set_var(name, value);
+/// }
+/// ```
+///
+pub fn rules_as_env<P: AsRef<Path>>(absolute_path: P) -> Result<Vec<(String, String)>, EnvError> {
+    let rules = parse_with_new_handle(absolute_path.as_ref(), |_| {})?;
+
+    let mut env_vars: Vec<(String, String, String)> = HashMap::from(rules)
+        .into_iter()
+        .map(|(rule_name, rule_value)| {
+            let env_var_name: String = rule_name
+                .chars()
+                .map(|c| match c {
+                    'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => c.to_ascii_uppercase(),
+                    _ => '_',
+                })
+                .collect();
+            (
+                format!("EDITORCONFIG_{}", env_var_name),
+                rule_name,
+                rule_value,
+            )
+        })
+        .collect();
+    env_vars.sort();
+
+    if let Some(pos) = env_vars.windows(2).position(|pair| pair[0].0 == pair[1].0) {
+        let name = env_vars[pos].0.clone();
+        let rule_names = env_vars[pos..]
+            .iter()
+            .take_while(|(env_var_name, _, _)| *env_var_name == name)
+            .map(|(_, rule_name, _)| rule_name.clone())
+            .collect();
+        return Err(EnvError::NameCollision { name, rule_names });
+    }
+
+    Ok(env_vars
+        .into_iter()
+        .map(|(env_var_name, _, rule_value)| (env_var_name, rule_value))
+        .collect())
+}
+
+/// Parses an absolute path and returns its rules as POSIX shell `export`
+/// statements, one per line
+///
+/// The variables are named like in [`rules_as_env`]. Each value is wrapped
+/// in single quotes and any `'` in it is written as `'\''`, so the output
+/// can be passed to `eval` even if the config files aren't trusted.
+///
+/// # Example
+///
+/// ```
+/// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+/// let exports = editorconfig_rs::rules_as_shell_exports(test_file_path).unwrap();
+/// print!("{}", exports);
+/// # assert!(exports.contains("export EDITORCONFIG_CHARSET='utf-8'\n"));
+/// ```
+///
+pub fn rules_as_shell_exports<P: AsRef<Path>>(absolute_path: P) -> Result<String, EnvError> {
+    let exports = rules_as_env(absolute_path)?
+        .into_iter()
+        .map(|(name, value)| format!("export {}='{}'\n", name, value.replace('\'', "'\\''")))
+        .collect();
+
+    Ok(exports)
+}
+
+/// Parses an absolute path and summarizes its rules as a [`PathClass`]
+///
+/// Unset or invalid rules are filled in with the defaults documented on
+/// [`PathClass`].
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::{Charset, EndOfLine};
+///
+/// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+/// let path_class = editorconfig_rs::classify_path(test_file_path).unwrap();
+/// assert_eq!(path_class.eol, EndOfLine::Lf);
+/// assert_eq!(path_class.charset, Charset::Utf8);
+/// ```
+///
+pub fn classify_path<P: AsRef<Path>>(absolute_path: P) -> Result<PathClass, ParseError> {
+    let rules = parse_with_new_handle(absolute_path.as_ref(), |_| {})?;
+    Ok(PathClass::from(&rules))
+}
+
+/// Parses an absolute path with a new [`EditorConfigHandle`], after passing
+/// the handle to `configure`, and returns its [rules](Rules)
+fn parse_with_new_handle<F: FnOnce(&EditorConfigHandle)>(
+    absolute_path: &Path,
+    configure: F,
+) -> Result<Rules, ParseError> {
+    // libeditorconfig only fails to create a handle if it's out of memory
+    let handle = EditorConfigHandle::new().map_err(|_| ParseError::MemoryError)?;
+    configure(&handle);
+
+    match handle.parse(absolute_path) {
+        Some(err) => Err(err),
+        None => Ok(handle.get_rules()),
+    }
+}
@@ -0,0 +1,129 @@
+use crate::EndOfLine;
+
+/// Number of line endings of each kind found by [`line_ending_report`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineEndingReport {
+    /// Number of `\n` line endings
+    pub lf: usize,
+    /// Number of `\r\n` line endings
+    pub crlf: usize,
+    /// Number of `\r` line endings
+    pub cr: usize,
+}
+
+impl LineEndingReport {
+    /// Returns the number of line endings of the given kind
+    #[must_use]
+    pub fn count(&self, end_of_line: EndOfLine) -> usize {
+        match end_of_line {
+            EndOfLine::Lf => self.lf,
+            EndOfLine::Crlf => self.crlf,
+            EndOfLine::Cr => self.cr,
+        }
+    }
+
+    /// Returns the total number of line endings
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.lf + self.crlf + self.cr
+    }
+
+    /// Returns the most frequent line ending or [`None`] if there are no line
+    /// endings at all
+    ///
+    /// Ties are resolved in the order LF, CRLF, CR.
+    #[must_use]
+    pub fn dominant(&self) -> Option<EndOfLine> {
+        [EndOfLine::Lf, EndOfLine::Crlf, EndOfLine::Cr]
+            .iter()
+            .copied()
+            .filter(|&end_of_line| self.count(end_of_line) > 0)
+            .fold(None, |dominant, end_of_line| match dominant {
+                Some(dominant) if self.count(dominant) >= self.count(end_of_line) => Some(dominant),
+                _ => Some(end_of_line),
+            })
+    }
+
+    /// Returns `true` if more than one kind of line ending was found
+    #[must_use]
+    pub fn is_mixed(&self) -> bool {
+        [self.lf, self.crlf, self.cr]
+            .iter()
+            .filter(|&&count| count > 0)
+            .count()
+            > 1
+    }
+}
+
+/// Counts the line endings of each kind in `content`
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::EndOfLine;
+///
+/// let report = editorconfig_rs::line_ending_report("a\nb\r\nc\n");
+/// assert_eq!(report.lf, 2);
+/// assert_eq!(report.crlf, 1);
+/// assert_eq!(report.dominant(), Some(EndOfLine::Lf));
+/// ```
+///
+#[must_use]
+pub fn line_ending_report(content: &str) -> LineEndingReport {
+    let mut report = LineEndingReport::default();
+    let mut bytes = content.bytes().peekable();
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'\n' => report.lf += 1,
+            b'\r' if bytes.peek() == Some(&b'\n') => {
+                bytes.next();
+                report.crlf += 1;
+            }
+            b'\r' => report.cr += 1,
+            _ => {}
+        }
+    }
+
+    report
+}
+
+/// Converts all line endings in `content` to `end_of_line`
+///
+/// Recognizes the same line endings as [`line_ending_report`], so `\r\n` is
+/// converted as a single line ending and content with mixed line endings
+/// ends up with exactly one line ending per line.
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::EndOfLine;
+///
+/// let content = editorconfig_rs::normalize_line_endings("a\nb\r\nc\r", EndOfLine::Crlf);
+/// assert_eq!(content, "a\r\nb\r\nc\r\n");
+/// ```
+///
+#[must_use]
+pub fn normalize_line_endings(content: &str, end_of_line: EndOfLine) -> String {
+    let line_ending = match end_of_line {
+        EndOfLine::Lf => "\n",
+        EndOfLine::Crlf => "\r\n",
+        EndOfLine::Cr => "\r",
+    };
+
+    let mut normalized = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => normalized.push_str(line_ending),
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                normalized.push_str(line_ending);
+            }
+            c => normalized.push(c),
+        }
+    }
+
+    normalized
+}
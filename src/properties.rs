@@ -0,0 +1,55 @@
+//! EditorConfig properties
+
+/// Names of all known EditorConfig properties that apply to files, i.e. all
+/// [`names`] except [`names::ROOT`]
+pub const KNOWN_PROPERTIES: &[&str] = &[
+    names::INDENT_STYLE,
+    names::INDENT_SIZE,
+    names::TAB_WIDTH,
+    names::END_OF_LINE,
+    names::CHARSET,
+    names::SPELLING_LANGUAGE,
+    names::TRIM_TRAILING_WHITESPACE,
+    names::INSERT_FINAL_NEWLINE,
+    names::MAX_LINE_LENGTH,
+];
+
+/// Names of the EditorConfig properties
+///
+/// # Example
+///
+/// ```
+/// use editorconfig_rs::properties::names;
+///
+/// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+/// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+/// let err = handle.parse(test_file_path);
+/// # assert!(err.is_none());
+/// let rules = handle.get_rules();
+/// let charset = rules.get(names::CHARSET);
+/// # assert_eq!(charset.unwrap(), "utf-8");
+/// ```
+///
+pub mod names {
+    /// Special property that stops the search for configuration files
+    pub const ROOT: &str = "root";
+    /// Indentation style, `tab` or `space`
+    pub const INDENT_STYLE: &str = "indent_style";
+    /// Number of columns per indentation level, or `tab`
+    pub const INDENT_SIZE: &str = "indent_size";
+    /// Number of columns representing a tab character
+    pub const TAB_WIDTH: &str = "tab_width";
+    /// Line ending, `lf`, `crlf` or `cr`
+    pub const END_OF_LINE: &str = "end_of_line";
+    /// Character set, e.g. `utf-8`
+    pub const CHARSET: &str = "charset";
+    /// Language for spell checking, e.g. `en-US`
+    pub const SPELLING_LANGUAGE: &str = "spelling_language";
+    /// Whether to remove whitespace before newline characters
+    pub const TRIM_TRAILING_WHITESPACE: &str = "trim_trailing_whitespace";
+    /// Whether a file should end with a newline
+    pub const INSERT_FINAL_NEWLINE: &str = "insert_final_newline";
+    /// Maximum line length; widely supported, but not part of the
+    /// EditorConfig specification
+    pub const MAX_LINE_LENGTH: &str = "max_line_length";
+}
@@ -0,0 +1,475 @@
+use std::{
+    collections::{hash_map, HashMap},
+    error::Error,
+    fmt,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use crate::{properties::names, EditorConfigHandle};
+
+/// Indentation style as defined by the `indent_style` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndentStyle {
+    /// Indent with hard tabs
+    Tab,
+    /// Indent with soft tabs (spaces)
+    Space,
+}
+
+/// Line ending as defined by the `end_of_line` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndOfLine {
+    /// Line feed (`\n`)
+    Lf,
+    /// Carriage return and line feed (`\r\n`)
+    Crlf,
+    /// Carriage return (`\r`)
+    Cr,
+}
+
+/// Character set as defined by the `charset` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Charset {
+    /// ISO-8859-1 (`latin1`)
+    Latin1,
+    /// UTF-8 without a byte order mark (`utf-8`)
+    Utf8,
+    /// UTF-8 with a byte order mark (`utf-8-bom`)
+    Utf8Bom,
+    /// UTF-16 big endian (`utf-16be`)
+    Utf16Be,
+    /// UTF-16 little endian (`utf-16le`)
+    Utf16Le,
+}
+
+/// Error returned when parsing an invalid property value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyError {
+    /// Name of the property
+    pub name: &'static str,
+    /// The invalid value
+    pub value: String,
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid {} value: {}", self.name, self.value)
+    }
+}
+
+impl Error for PropertyError {}
+
+impl FromStr for IndentStyle {
+    type Err = PropertyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "tab" => Ok(IndentStyle::Tab),
+            "space" => Ok(IndentStyle::Space),
+            _ => Err(PropertyError {
+                name: names::INDENT_STYLE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&str> for IndentStyle {
+    type Error = PropertyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromStr for EndOfLine {
+    type Err = PropertyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "lf" => Ok(EndOfLine::Lf),
+            "crlf" => Ok(EndOfLine::Crlf),
+            "cr" => Ok(EndOfLine::Cr),
+            _ => Err(PropertyError {
+                name: names::END_OF_LINE,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&str> for EndOfLine {
+    type Error = PropertyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromStr for Charset {
+    type Err = PropertyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "latin1" => Ok(Charset::Latin1),
+            "utf-8" => Ok(Charset::Utf8),
+            "utf-8-bom" => Ok(Charset::Utf8Bom),
+            "utf-16be" => Ok(Charset::Utf16Be),
+            "utf-16le" => Ok(Charset::Utf16Le),
+            _ => Err(PropertyError {
+                name: names::CHARSET,
+                value: s.to_owned(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&str> for Charset {
+    type Error = PropertyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Rules returned by [`EditorConfigHandle::get_rules`](crate::EditorConfigHandle::get_rules)
+///
+/// Dereferences to the underlying [`HashMap`] of rule names and values and
+/// can be iterated like it, so all map methods keep working, while providing
+/// typed accessors for the standard EditorConfig properties.
+///
+/// # Example
+///
+/// ```
+/// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+/// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+/// let err = handle.parse(test_file_path);
+/// # assert!(err.is_none());
+/// let rules = handle.get_rules();
+/// # assert_eq!(rules.get("charset").unwrap(), "utf-8");
+/// for (name, value) in &rules {
+///     println!("{} = {}", name, value);
+/// }
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rules(HashMap<String, String>);
+
+impl Rules {
+    /// Returns the number of rules
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no rules
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the names of all rules whose value is longer than `max_len`
+    /// bytes, sorted by name
+    ///
+    /// Tools that process untrusted repositories can use this to warn about
+    /// or ignore unreasonably long values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_file_path = std::fs::canonicalize("tests/🦀🚀").unwrap();
+    /// let err = handle.parse(test_file_path);
+    /// # assert!(err.is_none());
+    /// let rules = handle.get_rules();
+    /// for name in rules.values_longer_than(64) {
+    ///     eprintln!("Warning: value of {} is too long", name);
+    /// }
+    /// ```
+    ///
+    #[must_use]
+    pub fn values_longer_than(&self, max_len: usize) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .0
+            .iter()
+            .filter(|(_, value)| value.len() > max_len)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns the `indent_style` or [`None`] if it isn't set or its value is
+    /// invalid
+    #[must_use]
+    pub fn indent_style(&self) -> Option<IndentStyle> {
+        self.try_indent_style().ok().flatten()
+    }
+
+    /// Returns the `indent_style`, [`None`] if it isn't set or an error if its
+    /// value is invalid
+    ///
+    /// Unlike [`Rules::indent_style`], this lets strict tools reject an
+    /// invalid value instead of treating it as unset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_dir_path = std::fs::canonicalize("tests/rules").unwrap();
+    /// let err = handle.parse_dir_for(test_dir_path, "file.invalid");
+    /// # assert!(err.is_none());
+    /// let rules = handle.get_rules();
+    /// match rules.try_indent_style() {
+    ///     Ok(indent_style) => println!("indent_style: {:?}", indent_style),
+    ///     Err(err) => eprintln!("{}", err),
+    /// }
+    /// # assert!(rules.try_indent_style().is_err());
+    /// ```
+    ///
+    pub fn try_indent_style(&self) -> Result<Option<IndentStyle>, PropertyError> {
+        self.0
+            .get(names::INDENT_STYLE)
+            .map(|value| value.parse())
+            .transpose()
+    }
+
+    /// Returns the `end_of_line` or [`None`] if it isn't set or its value is
+    /// invalid
+    #[must_use]
+    pub fn end_of_line(&self) -> Option<EndOfLine> {
+        self.try_end_of_line().ok().flatten()
+    }
+
+    /// Returns the `end_of_line`, [`None`] if it isn't set or an error if its
+    /// value is invalid
+    ///
+    /// See [`Rules::try_indent_style`].
+    pub fn try_end_of_line(&self) -> Result<Option<EndOfLine>, PropertyError> {
+        self.0
+            .get(names::END_OF_LINE)
+            .map(|value| value.parse())
+            .transpose()
+    }
+
+    /// Returns the `charset` or [`None`] if it isn't set or its value is
+    /// invalid
+    #[must_use]
+    pub fn charset(&self) -> Option<Charset> {
+        self.try_charset().ok().flatten()
+    }
+
+    /// Returns the `charset`, [`None`] if it isn't set or an error if its
+    /// value is invalid
+    ///
+    /// See [`Rules::try_indent_style`].
+    pub fn try_charset(&self) -> Result<Option<Charset>, PropertyError> {
+        self.0
+            .get(names::CHARSET)
+            .map(|value| value.parse())
+            .transpose()
+    }
+
+    /// Returns the `indent_size` or [`None`] if it isn't set, is `tab` or
+    /// isn't a positive integer
+    ///
+    /// Unlike [`str::parse`], only plain ASCII digits without a leading `+`,
+    /// leading zeros or surrounding whitespace are accepted.
+    #[must_use]
+    pub fn indent_size(&self) -> Option<u32> {
+        self.0
+            .get(names::INDENT_SIZE)
+            .and_then(|value| parse_width(value))
+    }
+
+    /// Returns the `tab_width` or [`None`] if it isn't set or isn't a positive
+    /// integer
+    ///
+    /// See [`Rules::indent_size`].
+    #[must_use]
+    pub fn tab_width(&self) -> Option<u32> {
+        self.0
+            .get(names::TAB_WIDTH)
+            .and_then(|value| parse_width(value))
+    }
+
+    /// Returns the number of columns per indentation level or [`None`] to use
+    /// the editor's default
+    ///
+    /// Resolved in this order:
+    ///
+    /// 1. A numeric `indent_size`
+    /// 2. `tab_width` if `indent_size` is `tab`, or if `indent_size` isn't set
+    ///    and `indent_style` is `tab`
+    /// 3. [`None`] otherwise. If indentation uses the tab width, the editor's
+    ///    default tab width should be used then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use editorconfig_rs::Rules;
+    ///
+    /// let mut rules = HashMap::new();
+    /// rules.insert("indent_size".to_owned(), "tab".to_owned());
+    /// let mut rules = Rules::from(rules);
+    /// assert_eq!(rules.effective_indent_size(), None);
+    ///
+    /// rules.insert("tab_width".to_owned(), "8".to_owned());
+    /// assert_eq!(rules.effective_indent_size(), Some(8));
+    /// ```
+    ///
+    #[must_use]
+    pub fn effective_indent_size(&self) -> Option<u32> {
+        match self.indent_size() {
+            Some(indent_size) => Some(indent_size),
+            None if self.indents_by_tab_width() => self.tab_width(),
+            None => None,
+        }
+    }
+
+    /// Returns the number of columns a tab character takes up or [`None`] to
+    /// use the editor's default
+    ///
+    /// Resolved from `tab_width`, or else from a numeric `indent_size`.
+    #[must_use]
+    pub fn effective_tab_width(&self) -> Option<u32> {
+        self.tab_width().or_else(|| self.indent_size())
+    }
+
+    /// Returns `true` if `indent_size` is `tab`, or if it isn't set and
+    /// `indent_style` is `tab`, like `libeditorconfig` assumes
+    pub(crate) fn indents_by_tab_width(&self) -> bool {
+        match self.0.get(names::INDENT_SIZE) {
+            Some(indent_size) => indent_size.eq_ignore_ascii_case("tab"),
+            None => self.indent_style() == Some(IndentStyle::Tab),
+        }
+    }
+
+    /// Returns `true` if `tab_width` is set while `indent_style` is `space`
+    ///
+    /// `tab_width` has no effect when indenting with spaces, so setting it
+    /// there usually indicates a mistake in the config file. Since
+    /// `libeditorconfig` sets `tab_width` to a numeric `indent_size` if it
+    /// isn't set, `tab_width` is only reported if `indent_size` is unset or
+    /// differs from it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let handle = editorconfig_rs::EditorConfigHandle::new().unwrap();
+    /// let test_dir_path = std::fs::canonicalize("tests/rules").unwrap();
+    /// let err = handle.parse_dir_for(test_dir_path, "file.space");
+    /// # assert!(err.is_none());
+    /// if handle.get_rules().has_ineffective_tab_width() {
+    ///     eprintln!("Warning: tab_width has no effect with indent_style = space");
+    /// }
+    /// ```
+    ///
+    #[must_use]
+    pub fn has_ineffective_tab_width(&self) -> bool {
+        let tab_width = match self.0.get(names::TAB_WIDTH) {
+            Some(tab_width) => tab_width,
+            None => return false,
+        };
+
+        self.indent_style() == Some(IndentStyle::Space)
+            && self.0.get(names::INDENT_SIZE) != Some(tab_width)
+    }
+}
+
+/// Parses a positive integer consisting of ASCII digits only
+fn parse_width(value: &str) -> Option<u32> {
+    if value.starts_with('0') {
+        return None;
+    }
+
+    let mut width: u32 = 0;
+    for byte in value.bytes() {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        width = width.checked_mul(10)?.checked_add(u32::from(byte - b'0'))?;
+    }
+
+    if width == 0 {
+        None
+    } else {
+        Some(width)
+    }
+}
+
+impl Deref for Rules {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Rules {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<HashMap<String, String>> for Rules {
+    fn as_ref(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+}
+
+impl AsMut<HashMap<String, String>> for Rules {
+    fn as_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.0
+    }
+}
+
+impl IntoIterator for Rules {
+    type Item = (String, String);
+    type IntoIter = hash_map::IntoIter<String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Rules {
+    type Item = (&'a String, &'a String);
+    type IntoIter = hash_map::Iter<'a, String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl From<HashMap<String, String>> for Rules {
+    fn from(rules: HashMap<String, String>) -> Self {
+        Rules(rules)
+    }
+}
+
+impl From<&EditorConfigHandle> for Rules {
+    fn from(handle: &EditorConfigHandle) -> Self {
+        handle.get_rules()
+    }
+}
+
+impl From<Rules> for HashMap<String, String> {
+    fn from(rules: Rules) -> Self {
+        rules.0
+    }
+}
+
+impl PartialEq<HashMap<String, String>> for Rules {
+    fn eq(&self, other: &HashMap<String, String>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Rules> for HashMap<String, String> {
+    fn eq(&self, other: &Rules) -> bool {
+        *self == other.0
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    io::{self, BufRead, Write},
+    str,
+};
+
+use crate::{
+    line_ending_report, normalize_line_endings, properties::names, EndOfLine, LineEndingReport,
+    Rules,
+};
+
+/// Applies only the whitespace-related `rules` to `content`
+///
+/// Applies `end_of_line`, `trim_trailing_whitespace` and
+/// `insert_final_newline`, but leaves indentation and the charset untouched,
+/// since re-indenting can break languages where whitespace is significant.
+/// Unset or invalid rules are ignored. A final newline uses the `end_of_line`
+/// if set, or else the most frequent line ending in `content`; empty
+/// content stays empty.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use editorconfig_rs::Rules;
+///
+/// let mut rules = HashMap::new();
+/// rules.insert("trim_trailing_whitespace".to_owned(), "true".to_owned());
+/// rules.insert("insert_final_newline".to_owned(), "true".to_owned());
+/// let rules = Rules::from(rules);
+///
+/// let content = editorconfig_rs::normalize_whitespace_only("a  \n\tb\t", &rules);
+/// assert_eq!(content, "a\n\tb\n");
+/// ```
+///
+#[must_use]
+pub fn normalize_whitespace_only(content: &str, rules: &Rules) -> String {
+    let end_of_line = rules.end_of_line();
+    let mut content = match end_of_line {
+        Some(end_of_line) => normalize_line_endings(content, end_of_line),
+        None => content.to_owned(),
+    };
+
+    if rules
+        .get(names::TRIM_TRAILING_WHITESPACE)
+        .map(String::as_str)
+        == Some("true")
+    {
+        content = trim_trailing_whitespace(&content);
+    }
+
+    match rules.get(names::INSERT_FINAL_NEWLINE).map(String::as_str) {
+        Some("true") if !content.is_empty() && !content.ends_with(is_line_ending) => {
+            let end_of_line = end_of_line
+                .or_else(|| line_ending_report(&content).dominant())
+                .unwrap_or(EndOfLine::Lf);
+            content.push_str(line_ending(end_of_line));
+        }
+        Some("false") => content.truncate(content.trim_end_matches(is_line_ending).len()),
+        _ => {}
+    }
+
+    content
+}
+
+/// Same as [`normalize_whitespace_only`], but reads the content from
+/// `reader` and writes the result to `writer` line by line
+///
+/// Produces the same output as [`normalize_whitespace_only`] without loading
+/// the whole content into memory. Only line endings are held back until the
+/// next non-blank line, since blank lines at the end are removed if
+/// `insert_final_newline` is `false`. Returns an error of kind
+/// [`io::ErrorKind::InvalidData`] if the content isn't valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use editorconfig_rs::Rules;
+///
+/// let mut rules = HashMap::new();
+/// rules.insert("trim_trailing_whitespace".to_owned(), "true".to_owned());
+/// rules.insert("insert_final_newline".to_owned(), "false".to_owned());
+/// let rules = Rules::from(rules);
+///
+/// let mut content = Vec::new();
+/// editorconfig_rs::normalize_reader("a  \n\tb\t\n\n".as_bytes(), &mut content, &rules).unwrap();
+/// assert_eq!(content, b"a\n\tb");
+/// ```
+///
+pub fn normalize_reader<R: BufRead, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    rules: &Rules,
+) -> io::Result<()> {
+    let end_of_line = rules.end_of_line();
+    let trim = rules
+        .get(names::TRIM_TRAILING_WHITESPACE)
+        .map(String::as_str)
+        == Some("true");
+    let insert_final_newline = rules.get(names::INSERT_FINAL_NEWLINE).map(String::as_str);
+
+    // Line endings after the last non-blank line written so far
+    let mut pending_line_endings = String::new();
+    let mut report = LineEndingReport::default();
+    let mut has_content = false;
+    let mut buf = Vec::new();
+
+    // `\r\n` is never split, since `read_until` stops after the `\n`
+    while reader.read_until(b'\n', &mut buf)? > 0 {
+        let mut rest =
+            str::from_utf8(&buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        while !rest.is_empty() {
+            let line_len = rest.find(is_line_ending).unwrap_or(rest.len());
+            let (line, tail) = rest.split_at(line_len);
+            let ending_len = if tail.starts_with("\r\n") {
+                2
+            } else {
+                tail.len().min(1)
+            };
+            let (ending, tail) = tail.split_at(ending_len);
+            rest = tail;
+
+            let line = if trim {
+                line.trim_end_matches(char::is_whitespace)
+            } else {
+                line
+            };
+            if !line.is_empty() {
+                writer.write_all(pending_line_endings.as_bytes())?;
+                pending_line_endings.clear();
+                writer.write_all(line.as_bytes())?;
+                has_content = true;
+            }
+
+            let ending = match (end_of_line, ending) {
+                (_, "") => continue,
+                (Some(end_of_line), _) => line_ending(end_of_line),
+                (None, ending) => ending,
+            };
+            match ending {
+                "\n" => report.lf += 1,
+                "\r\n" => report.crlf += 1,
+                _ => report.cr += 1,
+            }
+            pending_line_endings.push_str(ending);
+        }
+
+        buf.clear();
+    }
+
+    match insert_final_newline {
+        Some("false") => {}
+        Some("true") if has_content && pending_line_endings.is_empty() => {
+            let end_of_line = end_of_line
+                .or_else(|| report.dominant())
+                .unwrap_or(EndOfLine::Lf);
+            writer.write_all(line_ending(end_of_line).as_bytes())?;
+        }
+        _ => writer.write_all(pending_line_endings.as_bytes())?,
+    }
+
+    writer.flush()
+}
+
+fn line_ending(end_of_line: EndOfLine) -> &'static str {
+    match end_of_line {
+        EndOfLine::Lf => "\n",
+        EndOfLine::Crlf => "\r\n",
+        EndOfLine::Cr => "\r",
+    }
+}
+
+fn is_line_ending(c: char) -> bool {
+    c == '\n' || c == '\r'
+}
+
+/// Removes whitespace before every line ending and at the end of `content`
+fn trim_trailing_whitespace(content: &str) -> String {
+    let mut trimmed = String::with_capacity(content.len());
+    let mut rest = content;
+
+    loop {
+        let line_len = rest.find(is_line_ending).unwrap_or(rest.len());
+        let (line, tail) = rest.split_at(line_len);
+        trimmed.push_str(line.trim_end_matches(|c: char| c.is_whitespace() && !is_line_ending(c)));
+
+        let ending_len = tail.len() - tail.trim_start_matches(is_line_ending).len();
+        if ending_len == 0 {
+            return trimmed;
+        }
+        trimmed.push_str(&tail[..ending_len]);
+        rest = &tail[ending_len..];
+    }
+}
@@ -0,0 +1,172 @@
+use editorconfig_rs::{
+    properties::names, Charset, EndOfLine, IndentStyle, Indentation, ParseError, PathClass, Rules,
+};
+use std::{collections::HashMap, fs};
+
+fn rules(properties: &[(&str, &str)]) -> Rules {
+    let rules: HashMap<String, String> = properties
+        .iter()
+        .map(|&(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    Rules::from(rules)
+}
+
+#[test]
+fn path_class_defaults() {
+    let path_class = PathClass::from(&Rules::default());
+    assert_eq!(
+        path_class,
+        PathClass {
+            indent: Indentation {
+                style: IndentStyle::Space,
+                size: 4,
+                tab_width: 4,
+            },
+            eol: EndOfLine::Lf,
+            charset: Charset::Utf8,
+            final_newline: false,
+        }
+    );
+
+    let path_class = PathClass::from(&rules(&[
+        (names::INDENT_STYLE, "invalid"),
+        (names::INDENT_SIZE, "+2"),
+        (names::TAB_WIDTH, "08"),
+        (names::CHARSET, "utf8"),
+        (names::INSERT_FINAL_NEWLINE, "yes"),
+    ]));
+    assert_eq!(path_class, PathClass::from(&Rules::default()));
+}
+
+#[test]
+fn path_class_with_defaults() {
+    let defaults = PathClass {
+        indent: Indentation {
+            style: IndentStyle::Tab,
+            size: 8,
+            tab_width: 8,
+        },
+        eol: EndOfLine::Crlf,
+        charset: Charset::Latin1,
+        final_newline: true,
+    };
+    assert_eq!(
+        PathClass::with_defaults(&Rules::default(), &defaults),
+        defaults
+    );
+
+    // `unset` clears a property back to the default
+    let path_class = PathClass::with_defaults(
+        &rules(&[
+            (names::INDENT_STYLE, "unset"),
+            (names::END_OF_LINE, "unset"),
+            (names::CHARSET, "unset"),
+            (names::INSERT_FINAL_NEWLINE, "unset"),
+        ]),
+        &defaults,
+    );
+    assert_eq!(path_class, defaults);
+
+    let path_class = PathClass::with_defaults(
+        &rules(&[
+            (names::INDENT_SIZE, "2"),
+            (names::CHARSET, "utf-8"),
+            (names::INSERT_FINAL_NEWLINE, "false"),
+        ]),
+        &defaults,
+    );
+    assert_eq!(
+        path_class,
+        PathClass {
+            indent: Indentation {
+                style: IndentStyle::Tab,
+                size: 2,
+                tab_width: 2,
+            },
+            charset: Charset::Utf8,
+            final_newline: false,
+            ..defaults
+        }
+    );
+}
+
+#[test]
+fn path_class_indentation() {
+    let path_class = PathClass::from(&rules(&[
+        (names::INDENT_STYLE, "tab"),
+        (names::INDENT_SIZE, "tab"),
+        (names::TAB_WIDTH, "8"),
+    ]));
+    assert_eq!(
+        path_class.indent,
+        Indentation {
+            style: IndentStyle::Tab,
+            size: 8,
+            tab_width: 8,
+        }
+    );
+
+    let path_class = PathClass::from(&rules(&[(names::INDENT_SIZE, "2")]));
+    assert_eq!(path_class.indent.size, 2);
+    assert_eq!(path_class.indent.tab_width, 2);
+
+    let path_class = PathClass::from(&rules(&[
+        (names::INDENT_SIZE, "2"),
+        (names::TAB_WIDTH, "8"),
+    ]));
+    assert_eq!(path_class.indent.size, 2);
+    assert_eq!(path_class.indent.tab_width, 8);
+}
+
+#[test]
+fn path_class_indent_size_tab() {
+    let defaults = PathClass {
+        indent: Indentation {
+            style: IndentStyle::Space,
+            size: 2,
+            tab_width: 8,
+        },
+        ..PathClass::default()
+    };
+
+    // Without a `tab_width`, the default tab width is used, not the default
+    // indent size
+    let path_class = PathClass::with_defaults(&rules(&[(names::INDENT_SIZE, "tab")]), &defaults);
+    assert_eq!(path_class.indent.size, 8);
+    assert_eq!(path_class.indent.tab_width, 8);
+
+    let path_class = PathClass::with_defaults(&rules(&[(names::INDENT_STYLE, "tab")]), &defaults);
+    assert_eq!(path_class.indent.size, 8);
+
+    // `tab_width` doesn't affect the indent size when indenting with spaces
+    let path_class = PathClass::with_defaults(
+        &rules(&[(names::INDENT_STYLE, "space"), (names::TAB_WIDTH, "4")]),
+        &defaults,
+    );
+    assert_eq!(path_class.indent.size, 2);
+    assert_eq!(path_class.indent.tab_width, 4);
+}
+
+#[test]
+fn path_class_properties() {
+    let path_class = PathClass::from(&rules(&[
+        (names::END_OF_LINE, "crlf"),
+        (names::CHARSET, "utf-16le"),
+        (names::INSERT_FINAL_NEWLINE, "true"),
+    ]));
+    assert_eq!(path_class.eol, EndOfLine::Crlf);
+    assert_eq!(path_class.charset, Charset::Utf16Le);
+    assert!(path_class.final_newline);
+}
+
+#[test]
+fn classify_path() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let path_class = editorconfig_rs::classify_path(test_file_path).unwrap();
+    assert_eq!(path_class.eol, EndOfLine::Lf);
+    assert_eq!(path_class.charset, Charset::Utf8);
+    assert!(path_class.final_newline);
+
+    let err = editorconfig_rs::classify_path(file!()).unwrap_err();
+    assert_eq!(err, ParseError::NotFullPathError);
+}
@@ -0,0 +1,109 @@
+use editorconfig_rs::{all_sections, find_nearest_config, has_any_config, SectionInfo};
+use std::{fs, path::Path};
+
+#[test]
+fn find_nearest_default_config() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let config_file_path = find_nearest_config(test_file_path, None).unwrap();
+    assert_eq!(
+        config_file_path,
+        fs::canonicalize("tests/.editorconfig").unwrap()
+    );
+
+    // The target doesn't have to exist
+    let test_file_path = fs::canonicalize("tests/rules").unwrap().join("file.tab");
+    let config_file_path = find_nearest_config(test_file_path, None).unwrap();
+    assert_eq!(
+        config_file_path,
+        fs::canonicalize("tests/rules/.editorconfig").unwrap()
+    );
+}
+
+#[test]
+fn find_nearest_custom_config() {
+    let test_file_path = fs::canonicalize("tests/rules").unwrap().join("file.tab");
+    let config_file_path = find_nearest_config(test_file_path, Some(".editorconfig.invalid"));
+    assert_eq!(
+        config_file_path,
+        Some(fs::canonicalize("tests/.editorconfig.invalid").unwrap())
+    );
+}
+
+#[test]
+fn find_nearest_missing_config() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let config_file_path = find_nearest_config(test_file_path, Some(".editorconfig.missing"));
+    assert!(config_file_path.is_none());
+}
+
+#[test]
+fn has_config() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    assert!(has_any_config(test_file_path));
+
+    let test_file_path = fs::canonicalize("tests/rules").unwrap().join("file.rs");
+    assert!(has_any_config(test_file_path));
+}
+
+#[test]
+fn all_sections_in_tree() {
+    let sections = all_sections("tests", None);
+    let sections: Vec<(&str, usize, usize)> = sections
+        .iter()
+        .map(|section| (section.glob.as_str(), section.line, section.property_count))
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            ("*", 3, 2),
+            ("*.rs", 7, 2),
+            ("*.tab", 1, 1),
+            ("*.space", 4, 1),
+            ("*.invalid", 7, 1),
+            ("*.custom", 10, 2),
+            ("*.collision", 14, 2),
+            ("*.quote", 18, 1),
+            ("*.space_size", 21, 2),
+            ("*.space_tab_width", 25, 2),
+            ("*.space_size_tab_width", 29, 3),
+            ("*.tab_tab_width", 34, 2),
+        ]
+    );
+
+    let sections = all_sections("tests/rules", None);
+    assert_eq!(
+        sections[0],
+        SectionInfo {
+            glob: "*.tab".to_owned(),
+            config_file: "tests/rules/.editorconfig".into(),
+            line: 1,
+            property_count: 1,
+        }
+    );
+}
+
+#[test]
+fn all_sections_custom_config() {
+    let sections = all_sections("tests/spec", Some("precedence.in"));
+    assert!(!sections.is_empty());
+
+    let mut config_files: Vec<&Path> = sections
+        .iter()
+        .map(|section| section.config_file.as_path())
+        .collect();
+    config_files.dedup();
+    assert_eq!(
+        config_files,
+        [
+            Path::new("tests/spec/nested/precedence.in"),
+            Path::new("tests/spec/precedence.in"),
+        ]
+    );
+
+    assert!(all_sections("tests", Some(".editorconfig.missing")).is_empty());
+}
+
+#[test]
+fn all_sections_missing_dir() {
+    assert!(all_sections("tests/missing", None).is_empty());
+}
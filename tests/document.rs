@@ -0,0 +1,107 @@
+use editorconfig_rs::{parse_config_document, ConfigProperty, ConfigSection, LineKind};
+use std::fs;
+
+fn property(key: &str, value: &str, line: usize) -> ConfigProperty {
+    ConfigProperty {
+        key: key.to_string(),
+        value: value.to_string(),
+        line,
+    }
+}
+
+#[test]
+fn parse_config_file() {
+    let config = fs::read_to_string("tests/.editorconfig").unwrap();
+    let document = parse_config_document(&config);
+
+    assert!(document.is_root());
+    assert_eq!(document.preamble, vec![property("root", "true", 1)]);
+    assert_eq!(
+        document.sections,
+        vec![
+            ConfigSection {
+                glob: "*".to_string(),
+                line: 3,
+                properties: vec![
+                    property("charset", "utf-8", 4),
+                    property("end_of_line", "lf", 5),
+                ],
+            },
+            ConfigSection {
+                glob: "*.rs".to_string(),
+                line: 7,
+                properties: vec![
+                    property("insert_final_newline", "true", 8),
+                    property("trim_trailing_whitespace", "true", 9),
+                ],
+            },
+        ]
+    );
+}
+
+#[test]
+fn keys_and_values_as_written() {
+    let document = parse_config_document("[*.{js,ts}]\n  Indent_Style=Tab  \nkey : a = b\n");
+    let section = &document.sections[0];
+    assert_eq!(section.glob, "*.{js,ts}");
+    assert_eq!(
+        section.properties,
+        vec![
+            property("Indent_Style", "Tab", 2),
+            property("key", "a = b", 3)
+        ]
+    );
+}
+
+#[test]
+fn comments_blank_and_invalid_lines() {
+    let config =
+        "\u{feff}# comment\n; comment\n\nroot = false\n[unterminated\ninvalid line\n[a]b]\n";
+    let document = parse_config_document(config);
+
+    assert!(!document.is_root());
+    assert_eq!(document.preamble, vec![property("root", "false", 4)]);
+    assert_eq!(document.sections.len(), 1);
+    assert_eq!(document.sections[0].glob, "a]b");
+    assert_eq!(document.sections[0].line, 7);
+}
+
+#[test]
+fn line_kinds() {
+    let config = "\u{feff}# comment\n  ; comment\n\t\nroot = true\n[*.rs]\nindent_size: 4\n[unterminated\ninvalid line\n";
+    let document = parse_config_document(config);
+
+    assert_eq!(
+        document.lines,
+        vec![
+            LineKind::HashComment,
+            LineKind::SemicolonComment,
+            LineKind::Blank,
+            LineKind::Property,
+            LineKind::Section,
+            LineKind::Property,
+            LineKind::Invalid,
+            LineKind::Invalid,
+        ]
+    );
+}
+
+#[test]
+fn empty_document() {
+    let document = parse_config_document("");
+    assert!(!document.is_root());
+    assert!(document.preamble.is_empty());
+    assert!(document.sections.is_empty());
+    assert!(document.lines.is_empty());
+}
+
+#[test]
+fn empty_config_files() {
+    for config_file_path in ["tests/.editorconfig.empty", "tests/.editorconfig.comments"] {
+        let config = fs::read_to_string(config_file_path).unwrap();
+        let document = parse_config_document(&config);
+        assert!(document.preamble.is_empty());
+        assert!(document.sections.is_empty());
+        assert!(!document.lines.contains(&LineKind::Invalid));
+    }
+}
@@ -1,6 +1,9 @@
-use editorconfig_rs::{EditorConfigHandle, ParseError, Version};
+use editorconfig_rs::{
+    Charset, EditorConfigHandle, EndOfLine, EnvError, HandleError, IndentStyle, ParseError,
+    ResolveError, Rules, Version, VersionError,
+};
 use rand::Rng;
-use std::{collections::HashMap, fs, os::raw::c_int, path};
+use std::{collections::HashMap, ffi::CStr, fs, io, os::raw::c_int, path, time::Duration};
 
 const DEFAULT_CONFIG_FILENAME: &str = ".editorconfig";
 
@@ -11,10 +14,45 @@ fn new_handle() {
     }
 }
 
+#[test]
+fn build_handle() {
+    let version = Version::new(0, 12, 5);
+    let handle = EditorConfigHandle::builder()
+        .config_filename(DEFAULT_CONFIG_FILENAME)
+        .version(version)
+        .build()
+        .unwrap();
+
+    assert_eq!(handle.get_version(), version);
+    assert_eq!(
+        handle.get_config_filename().unwrap(),
+        DEFAULT_CONFIG_FILENAME
+    );
+
+    let handle = EditorConfigHandle::builder().build().unwrap();
+    assert_eq!(handle.get_version(), Version::ZERO);
+    assert!(handle.get_config_filename().is_none());
+}
+
+#[test]
+fn build_handle_invalid_config_filename() {
+    let invalid_config_filename = ".editor\0config";
+    let err = EditorConfigHandle::builder()
+        .config_filename(invalid_config_filename)
+        .build()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        HandleError::InvalidConfigFilename(invalid_config_filename.to_string())
+    );
+}
+
 #[test]
 fn get_version() {
     let handle = EditorConfigHandle::new().unwrap();
-    let expected_version = Version::new(0, 0, 0);
+    let expected_version = Version::ZERO;
     assert_eq!(handle.get_version(), expected_version);
 }
 
@@ -36,11 +74,29 @@ fn set_get_version() {
     }
 }
 
+#[test]
+fn configured_version() {
+    let handle = EditorConfigHandle::new().unwrap();
+    assert!(handle.configured_version().is_none());
+
+    let version = Version::new(0, 12, 5);
+    handle.set_version(version);
+    assert_eq!(handle.configured_version(), Some(version));
+    assert_eq!(handle.configured_version(), Some(handle.get_version()));
+
+    let handle = EditorConfigHandle::builder()
+        .version(Version::new(0u8, 9, 0))
+        .build()
+        .unwrap();
+    assert_eq!(handle.configured_version(), Some(Version::new(0, 9, 0)));
+}
+
 #[test]
 fn get_config_filename() {
     let handle = EditorConfigHandle::new().unwrap();
     let config_filename = handle.get_config_filename();
     assert!(config_filename.is_none());
+    assert_eq!(handle.try_get_config_filename(), Ok(None));
 }
 
 #[test]
@@ -50,6 +106,26 @@ fn set_get_config_filename() {
 
     let config_filename = handle.get_config_filename().unwrap();
     assert_eq!(config_filename, DEFAULT_CONFIG_FILENAME);
+
+    let config_filename = handle.try_get_config_filename();
+    assert_eq!(
+        config_filename,
+        Ok(Some(DEFAULT_CONFIG_FILENAME.to_owned()))
+    );
+}
+
+#[test]
+fn clear_config_filename() {
+    let mut handle = EditorConfigHandle::new().unwrap();
+    handle.set_config_filename(".editorconfig.invalid");
+    handle.clear_config_filename();
+    assert!(handle.get_config_filename().is_none());
+
+    // Parsing uses the default and valid config file again
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+    assert_eq!(handle.get_rule_count(), 4);
 }
 
 #[test]
@@ -88,6 +164,246 @@ fn parse_emoji_path() {
     assert_eq!(rules.len(), 2);
 }
 
+#[test]
+fn parse_relative_to() {
+    let handle = EditorConfigHandle::new().unwrap();
+    let result = handle.parse_relative_to("tests", "editorconfig.rs");
+    assert!(result.is_ok());
+    assert_eq!(handle.get_rule_count(), 4);
+
+    let result = handle.parse_relative_to("tests/rules", "../editorconfig.rs");
+    assert!(result.is_ok());
+    assert_eq!(handle.get_rule_count(), 4);
+
+    let err = handle.parse_relative_to("tests", "missing.rs").unwrap_err();
+    let ResolveError::Io(err) = err else {
+        panic!("Expected ResolveError::Io");
+    };
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn parse_relative_to_parse_error() {
+    let mut handle = EditorConfigHandle::new().unwrap();
+    handle.set_config_filename(".editorconfig.invalid");
+
+    let err = handle
+        .parse_relative_to("tests", "editorconfig.rs")
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Failed to parse config file at line 3");
+    let ResolveError::Parse(err) = err else {
+        panic!("Expected ResolveError::Parse");
+    };
+    assert_eq!(err, ParseError::LineError(3));
+}
+
+#[test]
+fn parse_timed() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+
+    let handle = EditorConfigHandle::new().unwrap();
+    let (err, duration) = handle.parse_timed(test_file_path);
+    assert!(err.is_none());
+    assert!(duration > Duration::ZERO);
+
+    let (err, _) = handle.parse_timed(file!());
+    assert_eq!(err, Some(ParseError::NotFullPathError));
+}
+
+#[test]
+fn get_rules_into() {
+    let handle = EditorConfigHandle::new().unwrap();
+    let mut rules = HashMap::new();
+    rules.insert("stale_rule".to_string(), "true".to_string());
+
+    let emoji_test_path = fs::canonicalize("tests/🦀🚀").unwrap();
+    let err = handle.parse(emoji_test_path);
+    assert!(err.is_none());
+
+    // Previous contents of the map are replaced
+    handle.get_rules_into(&mut rules);
+    assert_eq!(rules, handle.get_rules());
+    assert!(!rules.contains_key("stale_rule"));
+
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+
+    handle.get_rules_into(&mut rules);
+    assert_eq!(rules.len(), 4);
+    assert_eq!(rules, handle.get_rules());
+}
+
+#[test]
+fn get_rules_from_handle() {
+    let handle = EditorConfigHandle::new().unwrap();
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+
+    let rules: Rules = (&handle).into();
+    assert_eq!(rules.len(), 4);
+    assert_eq!(rules, handle.get_rules());
+    assert_eq!(Rules::from(&handle), rules);
+}
+
+#[test]
+fn get_rules_indent_style() {
+    let rules_test_dir = fs::canonicalize("tests/rules").unwrap();
+    let handle = EditorConfigHandle::new().unwrap();
+
+    let expected_indent_styles = [
+        ("file.tab", Some(IndentStyle::Tab)),
+        ("file.space", Some(IndentStyle::Space)),
+        ("file.invalid", None),
+        ("file.rs", None),
+    ];
+
+    for (filename, expected_indent_style) in expected_indent_styles {
+        let err = handle.parse(rules_test_dir.join(filename));
+        assert!(err.is_none());
+
+        let rules = handle.get_rules();
+        assert_eq!(rules.indent_style(), expected_indent_style);
+    }
+}
+
+#[test]
+fn parse_dir_for() {
+    let rules_test_dir = fs::canonicalize("tests/rules").unwrap();
+    let handle = EditorConfigHandle::new().unwrap();
+
+    // The directory itself only matches `[*]` in the parent directory
+    let err = handle.parse(&rules_test_dir);
+    assert!(err.is_none());
+    assert_eq!(handle.get_rules().indent_style(), None);
+    assert_eq!(handle.get_rules().end_of_line(), Some(EndOfLine::Lf));
+
+    let err = handle.parse_dir_for(&rules_test_dir, "does_not_exist.tab");
+    assert!(err.is_none());
+    assert_eq!(handle.get_rules().indent_style(), Some(IndentStyle::Tab));
+    assert_eq!(handle.get_rules().end_of_line(), Some(EndOfLine::Lf));
+
+    let err = handle.parse_dir_for("relative/dir", "file.tab");
+    assert_eq!(err, Some(ParseError::NotFullPathError));
+}
+
+#[test]
+fn parse_dir_for_invalid_filename() {
+    let rules_test_dir = fs::canonicalize("tests/rules").unwrap();
+    let handle = EditorConfigHandle::new().unwrap();
+
+    for filename in [
+        "../editorconfig.rs",
+        "/file.tab",
+        "sub/file.tab",
+        "",
+        ".",
+        "..",
+    ] {
+        let err = handle.parse_dir_for(&rules_test_dir, filename);
+        assert_eq!(
+            err,
+            Some(ParseError::InvalidFilenameError),
+            "{:?}",
+            filename
+        );
+    }
+
+    let err = ParseError::InvalidFilenameError;
+    assert_eq!(editorconfig_rs::get_error_message(err), None);
+    assert_eq!(err.to_string(), "Filename must be a single path component");
+}
+
+#[test]
+fn get_standard_rules() {
+    let rules_test_dir = fs::canonicalize("tests/rules").unwrap();
+    let handle = EditorConfigHandle::new().unwrap();
+
+    let err = handle.parse_dir_for(rules_test_dir, "file.custom");
+    assert!(err.is_none());
+
+    let rules = handle.get_rules();
+    assert_eq!(rules.get("custom_property").unwrap(), "MyValue");
+
+    let standard_rules = handle.get_standard_rules();
+    assert_eq!(standard_rules.len(), rules.len() - 1);
+    assert_eq!(standard_rules.indent_style(), Some(IndentStyle::Space));
+    assert!(!standard_rules.contains_key("custom_property"));
+}
+
+#[test]
+fn get_rules_map_access() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+
+    let handle = EditorConfigHandle::new().unwrap();
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+
+    let rules = handle.get_rules();
+    assert_eq!(rules.get("charset").unwrap(), "utf-8");
+    assert_eq!(rules.get("indent_style"), None);
+    assert_eq!(rules.end_of_line(), Some(EndOfLine::Lf));
+    assert_eq!(rules.charset(), Some(Charset::Utf8));
+    assert!(rules.contains_key("end_of_line"));
+    assert_eq!(rules.keys().count(), rules.len());
+
+    let rules_ref: &HashMap<String, String> = rules.as_ref();
+    assert_eq!(rules_ref.len(), 4);
+
+    let mut rule_names: Vec<&String> = (&rules).into_iter().map(|(name, _)| name).collect();
+    rule_names.sort();
+    assert_eq!(
+        rule_names,
+        [
+            "charset",
+            "end_of_line",
+            "insert_final_newline",
+            "trim_trailing_whitespace"
+        ]
+    );
+
+    let rule_count = rules.clone().into_iter().count();
+    assert_eq!(rule_count, 4);
+
+    let rules: HashMap<String, String> = rules.into();
+    assert_eq!(rules.len(), 4);
+}
+
+#[test]
+fn get_or() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+
+    let handle = EditorConfigHandle::new().unwrap();
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+
+    assert!(handle.get_or("insert_final_newline", false));
+    assert_eq!(handle.get_or("end_of_line", EndOfLine::Cr), EndOfLine::Lf);
+    assert_eq!(handle.get_or("charset", String::new()), "utf-8");
+
+    // Missing rules and values that can't be parsed fall back to the default
+    assert_eq!(handle.get_or("my_custom_margin", 4u32), 4);
+    assert_eq!(handle.get_or("charset", 8u32), 8);
+}
+
+#[test]
+fn parse_empty_config_files() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+
+    // Empty and comment-only config files are valid and contain no rules
+    for config_filename in [".editorconfig.empty", ".editorconfig.comments"] {
+        let mut handle = EditorConfigHandle::new().unwrap();
+        handle.set_config_filename(config_filename);
+
+        let err = handle.parse(&test_file_path);
+        assert!(err.is_none());
+        assert!(handle.get_error_file().is_none());
+        assert_eq!(handle.get_rule_count(), 0);
+        assert!(handle.get_rules().is_empty());
+    }
+}
+
 #[test]
 fn no_parse_get_rules() {
     let handle = EditorConfigHandle::new().unwrap();
@@ -114,6 +430,24 @@ fn version_too_new_error() {
     assert_eq!(err, ParseError::VersionTooNewError);
 }
 
+#[test]
+fn last_error_code() {
+    let handle = EditorConfigHandle::new().unwrap();
+    assert_eq!(handle.last_error_code(), None);
+
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+    assert_eq!(handle.last_error_code(), Some(0));
+
+    let err = handle.parse(file!());
+    assert_eq!(err, Some(ParseError::NotFullPathError));
+    assert_eq!(
+        handle.last_error_code(),
+        Some(editorconfig_sys::EDITORCONFIG_PARSE_NOT_FULL_PATH)
+    );
+}
+
 #[test]
 fn get_error_message_parse_error() {
     let mut rng = rand::thread_rng();
@@ -140,6 +474,15 @@ fn get_error_message_relative_path_error() {
     );
 }
 
+#[test]
+fn parse_error_display() {
+    let err = ParseError::LineError(23);
+    assert_eq!(err.to_string(), "Failed to parse config file at line 23");
+
+    let err = ParseError::NotFullPathError;
+    assert_eq!(err.to_string(), "Input file must be a full path name.");
+}
+
 #[test]
 fn get_error_message_memory_error() {
     let memory_err_msg = editorconfig_rs::get_error_message(ParseError::MemoryError).unwrap();
@@ -160,6 +503,56 @@ fn get_error_message_version_error() {
     );
 }
 
+#[test]
+fn stopped_at_root() {
+    let mut handle = EditorConfigHandle::new().unwrap();
+    assert!(!handle.stopped_at_root());
+
+    let test_file_path = fs::canonicalize("tests/editorconfig.rs").unwrap();
+    let err = handle.parse(&test_file_path);
+    assert!(err.is_none());
+    assert!(handle.stopped_at_root());
+
+    // No file named `.editorconfig.empty` above it contains `root = true`
+    handle.set_config_filename(".editorconfig.empty");
+    let err = handle.parse(&test_file_path);
+    assert!(err.is_none());
+    assert!(!handle.stopped_at_root());
+
+    let err = handle.parse("relative/path");
+    assert!(err.is_some());
+    assert!(!handle.stopped_at_root());
+
+    // Not cached from the previous successful parse
+    handle.set_config_filename(".editorconfig");
+    let err = handle.parse(&test_file_path);
+    assert!(err.is_none());
+    assert!(handle.stopped_at_root());
+    handle.set_config_filename(".editorconfig.invalid");
+    let err = handle.parse(&test_file_path);
+    assert!(err.is_some());
+    assert!(!handle.stopped_at_root());
+}
+
+#[test]
+fn target_directory() {
+    let handle = EditorConfigHandle::new().unwrap();
+    assert_eq!(handle.target_directory(), None);
+
+    let test_dir_path = fs::canonicalize("tests").unwrap();
+    let err = handle.parse(test_dir_path.join("editorconfig.rs"));
+    assert!(err.is_none());
+    assert_eq!(handle.target_directory(), Some(test_dir_path.clone()));
+
+    let err = handle.parse_dir_for(test_dir_path.join("rules"), "file.tab");
+    assert!(err.is_none());
+    assert_eq!(handle.target_directory(), Some(test_dir_path.join("rules")));
+
+    let err = handle.parse("relative/path");
+    assert!(err.is_some());
+    assert_eq!(handle.target_directory(), None);
+}
+
 #[test]
 fn get_error_file() {
     let mut handle = EditorConfigHandle::new().unwrap();
@@ -179,6 +572,7 @@ fn get_error_file() {
     // No error, no error file
     let err_file_path = handle.get_error_file();
     assert!(err_file_path.is_none());
+    assert_eq!(handle.try_get_error_file(), Ok(None));
 
     // Set invalid config filename
     handle.set_config_filename(invalid_config_filename);
@@ -192,6 +586,9 @@ fn get_error_file() {
 
     let err_file_path = handle.get_error_file().unwrap();
     assert_eq!(err_file_path, invalid_config_file_path);
+
+    let err_file_path = handle.try_get_error_file();
+    assert_eq!(err_file_path, Ok(Some(invalid_config_file_path)));
 }
 
 #[test]
@@ -207,6 +604,24 @@ fn get_rule_count() {
     assert_eq!(handle.get_rule_count(), 4);
 }
 
+#[test]
+fn into_raw() {
+    let mut handle = EditorConfigHandle::new().unwrap();
+    handle.set_config_filename(DEFAULT_CONFIG_FILENAME);
+
+    let raw_handle = handle.into_raw();
+    assert!(!raw_handle.is_null());
+
+    // The raw handle, including its config filename, outlives the wrapper
+    let config_filename =
+        unsafe { editorconfig_sys::editorconfig_handle_get_conf_file_name(raw_handle) };
+    let config_filename = unsafe { CStr::from_ptr(config_filename) };
+    assert_eq!(config_filename.to_str().unwrap(), DEFAULT_CONFIG_FILENAME);
+
+    let err = unsafe { editorconfig_sys::editorconfig_handle_destroy(raw_handle) };
+    assert_eq!(err, 0);
+}
+
 #[test]
 fn lib_get_version() {
     let version = editorconfig_rs::get_version();
@@ -217,12 +632,106 @@ fn lib_get_version() {
     assert!(version.patch >= 5);
 }
 
+#[test]
+fn max_supported_version() {
+    let version = editorconfig_rs::get_version();
+    let too_new = version.next_minor();
+
+    let candidates = [Version::ZERO, version.without_patch(), version, too_new];
+    assert_eq!(
+        editorconfig_rs::max_supported_version(&candidates),
+        Some(version)
+    );
+    assert_eq!(
+        editorconfig_rs::max_supported_version(&[Version::ZERO, too_new]),
+        Some(Version::ZERO)
+    );
+    assert_eq!(editorconfig_rs::max_supported_version(&[too_new]), None);
+    assert_eq!(editorconfig_rs::max_supported_version(&[]), None);
+}
+
+#[test]
+fn resolve_diff() {
+    let test_file_path = fs::canonicalize("tests/rules").unwrap().join("file.tab");
+
+    // libeditorconfig only sets `indent_size = tab` for `indent_style = tab`
+    // since EditorConfig version 0.10.0
+    let diff = editorconfig_rs::resolve_diff(
+        &test_file_path,
+        Version::new(0, 9, 0),
+        Version::new(0, 10, 0),
+    )
+    .unwrap();
+    assert_eq!(
+        diff,
+        vec![("indent_size".to_string(), None, Some("tab".to_string()))]
+    );
+
+    let version = Version::new(0, 10, 0);
+    let diff = editorconfig_rs::resolve_diff(&test_file_path, version, version).unwrap();
+    assert!(diff.is_empty());
+
+    let max_version = Version::new(c_int::MAX, c_int::MAX, c_int::MAX);
+    let err = editorconfig_rs::resolve_diff(&test_file_path, version, max_version).unwrap_err();
+    assert_eq!(err, ParseError::VersionTooNewError);
+}
+
+#[test]
+fn rules_as_env() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let env_vars = editorconfig_rs::rules_as_env(test_file_path).unwrap();
+
+    let expected_env_vars = [
+        ("EDITORCONFIG_CHARSET", "utf-8"),
+        ("EDITORCONFIG_END_OF_LINE", "lf"),
+        ("EDITORCONFIG_INSERT_FINAL_NEWLINE", "true"),
+        ("EDITORCONFIG_TRIM_TRAILING_WHITESPACE", "true"),
+    ];
+    let expected_env_vars: Vec<(String, String)> = expected_env_vars
+        .iter()
+        .map(|&(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    assert_eq!(env_vars, expected_env_vars);
+
+    let err = editorconfig_rs::rules_as_env(file!()).unwrap_err();
+    assert_eq!(err, EnvError::Parse(ParseError::NotFullPathError));
+
+    let test_file_path = fs::canonicalize("tests/rules")
+        .unwrap()
+        .join("file.collision");
+    let err = editorconfig_rs::rules_as_env(test_file_path).unwrap_err();
+    assert_eq!(
+        err,
+        EnvError::NameCollision {
+            name: "EDITORCONFIG_FOO_BAR".to_string(),
+            rule_names: vec!["foo-bar".to_string(), "foo.bar".to_string()],
+        }
+    );
+}
+
+#[test]
+fn rules_as_shell_exports() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    let exports = editorconfig_rs::rules_as_shell_exports(test_file_path).unwrap();
+    assert_eq!(
+        exports,
+        "export EDITORCONFIG_CHARSET='utf-8'\n\
+         export EDITORCONFIG_END_OF_LINE='lf'\n\
+         export EDITORCONFIG_INSERT_FINAL_NEWLINE='true'\n\
+         export EDITORCONFIG_TRIM_TRAILING_WHITESPACE='true'\n"
+    );
+
+    let test_file_path = fs::canonicalize("tests/rules").unwrap().join("file.quote");
+    let exports = editorconfig_rs::rules_as_shell_exports(test_file_path).unwrap();
+    assert!(exports.contains("export EDITORCONFIG_QUOTE='it'\\''s'\n"));
+}
+
 #[test]
 fn copy_clone_versions() {
     // Testing the `Clone` and `Copy` traits
     let mut version = Version::new(0, 1, 2);
     let mut version_copy = version;
-    let version_clone = version.clone();
+    let version_clone = Clone::clone(&version);
 
     assert_eq!(version, version_copy);
     assert_eq!(version, version_clone);
@@ -247,9 +756,73 @@ fn compare_versions() {
     assert!(Version::new(0, 1, 0) <= Version::new(1, 0, 0));
 }
 
+#[test]
+fn version_zero() {
+    assert_eq!(Version::ZERO, Version::new(0, 0, 0));
+    assert!(Version::ZERO <= Version::new(0, 0, 1));
+}
+
+#[test]
+fn version_ignoring_patch() {
+    let version = Version::new(0, 12, 5);
+    assert!(version.matches_minor(&Version::new(0, 12, 0)));
+    assert!(version.matches_minor(&version));
+    assert!(!version.matches_minor(&Version::new(0, 11, 5)));
+    assert!(!version.matches_minor(&Version::new(1, 12, 5)));
+
+    assert_eq!(version.without_patch(), Version::new(0, 12, 0));
+    assert!(version.without_patch() >= Version::new(0, 12, 0));
+    assert!(version.without_patch() < Version::new(0, 12, 1));
+}
+
+#[test]
+fn next_versions() {
+    let version = Version::new(0, 12, 5);
+    assert_eq!(version.next_minor(), Version::new(0, 13, 0));
+    assert_eq!(version.next_patch(), Version::new(0, 12, 6));
+    assert!(version.next_minor() > version);
+    assert!(version.next_patch() > version);
+
+    let max = Version::new(0, c_int::MAX, c_int::MAX);
+    assert_eq!(max.next_minor(), max);
+    let version = Version::new(0, c_int::MAX, 5);
+    assert_eq!(version.next_minor(), version);
+    assert_eq!(max.next_patch(), max);
+}
+
+#[test]
+fn version_from_u64_components() {
+    assert_eq!(
+        Version::from_u64_components(0, 12, 5),
+        Ok(Version::new(0, 12, 5))
+    );
+
+    let max = c_int::MAX as u64;
+    assert_eq!(
+        Version::from_u64_components(max, max, max),
+        Ok(Version::new(c_int::MAX, c_int::MAX, c_int::MAX))
+    );
+
+    let err = Version::from_u64_components(0, 12, max + 1).unwrap_err();
+    assert_eq!(
+        err,
+        VersionError {
+            component: "patch",
+            value: max + 1
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        format!("Invalid patch version number: {}", max + 1)
+    );
+
+    let err = Version::from_u64_components(u64::MAX, 0, 0).unwrap_err();
+    assert_eq!(err.component, "major");
+}
+
 #[test]
 #[should_panic(expected = "Version numbers cannot be negative")]
 fn safe_version() {
     // Testing the "safe" `Version` constructor
-    Version::new(-1, -2, -3);
+    let _ = Version::new(-1, -2, -3);
 }
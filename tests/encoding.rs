@@ -0,0 +1,122 @@
+use editorconfig_rs::{
+    charset_conflict, encode_with_charset, properties::names, Charset, CharsetConflict,
+    EncodeError, Rules,
+};
+use std::{collections::HashMap, io};
+
+#[test]
+fn encode_utf8() {
+    assert_eq!(
+        encode_with_charset("a€\n", Charset::Utf8).unwrap(),
+        "a€\n".as_bytes()
+    );
+    assert_eq!(
+        encode_with_charset("a€\n", Charset::Utf8Bom).unwrap(),
+        [0xEF, 0xBB, 0xBF, b'a', 0xE2, 0x82, 0xAC, b'\n']
+    );
+    assert_eq!(
+        encode_with_charset("", Charset::Utf8Bom).unwrap(),
+        [0xEF, 0xBB, 0xBF]
+    );
+}
+
+#[test]
+fn encode_utf16() {
+    // U+1F980 is encoded as the surrogate pair D83E DD80
+    assert_eq!(
+        encode_with_charset("a🦀", Charset::Utf16Be).unwrap(),
+        [0xFE, 0xFF, 0x00, b'a', 0xD8, 0x3E, 0xDD, 0x80]
+    );
+    assert_eq!(
+        encode_with_charset("a🦀", Charset::Utf16Le).unwrap(),
+        [0xFF, 0xFE, b'a', 0x00, 0x3E, 0xD8, 0x80, 0xDD]
+    );
+}
+
+#[test]
+fn encode_latin1() {
+    assert_eq!(
+        encode_with_charset("aé\u{FF}", Charset::Latin1).unwrap(),
+        [b'a', 0xE9, 0xFF]
+    );
+
+    let err = encode_with_charset("aé€", Charset::Latin1).unwrap_err();
+    assert_eq!(
+        err,
+        EncodeError {
+            character: '€',
+            index: 3
+        }
+    );
+    assert_eq!(err.to_string(), "Cannot encode '€' at byte 3 as latin1");
+}
+
+fn charset_rules(charset: &str) -> Rules {
+    let mut rules = HashMap::new();
+    rules.insert(names::CHARSET.to_string(), charset.to_string());
+    Rules::from(rules)
+}
+
+#[test]
+fn charset_conflicts() {
+    let rules = charset_rules("utf-8-bom");
+    assert_eq!(
+        charset_conflict("tests/bom/utf-8-bom.txt", &rules).unwrap(),
+        None
+    );
+    assert_eq!(
+        charset_conflict("tests/bom/none.txt", &rules).unwrap(),
+        Some(CharsetConflict {
+            declared: Charset::Utf8Bom,
+            detected: None
+        })
+    );
+
+    let rules = charset_rules("latin1");
+    assert_eq!(
+        charset_conflict("tests/bom/none.txt", &rules).unwrap(),
+        None
+    );
+    assert_eq!(
+        charset_conflict("tests/bom/short.txt", &rules).unwrap(),
+        None
+    );
+    assert_eq!(
+        charset_conflict("tests/bom/utf-8-bom.txt", &rules).unwrap(),
+        Some(CharsetConflict {
+            declared: Charset::Latin1,
+            detected: Some(Charset::Utf8Bom)
+        })
+    );
+
+    let rules = charset_rules("utf-16be");
+    assert_eq!(
+        charset_conflict("tests/bom/utf-16le.txt", &rules).unwrap(),
+        Some(CharsetConflict {
+            declared: Charset::Utf16Be,
+            detected: Some(Charset::Utf16Le)
+        })
+    );
+    let rules = charset_rules("utf-16le");
+    assert_eq!(
+        charset_conflict("tests/bom/utf-16le.txt", &rules).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn charset_conflict_without_charset() {
+    let rules = charset_rules("invalid");
+    assert_eq!(
+        charset_conflict("tests/bom/utf-8-bom.txt", &rules).unwrap(),
+        None
+    );
+    assert_eq!(
+        charset_conflict("tests/bom/missing.txt", &Rules::default()).unwrap(),
+        None
+    );
+
+    let rules = charset_rules("utf-8");
+    let err = charset_conflict("tests/bom/missing.txt", &rules).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
@@ -0,0 +1,90 @@
+use editorconfig_rs::{format_file_in_place, FormatError, ParseError};
+use std::{env, fs, path::PathBuf, process};
+
+/// Creates an empty directory with a root `.editorconfig` file
+fn format_test_dir(name: &str, config: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("editorconfig-rs-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join(".editorconfig"),
+        format!("root = true\n\n{}", config),
+    )
+    .unwrap();
+    fs::canonicalize(dir).unwrap()
+}
+
+#[test]
+fn format_utf8_file() {
+    let dir = format_test_dir(
+        "format-utf8",
+        "[*]\nend_of_line = crlf\ntrim_trailing_whitespace = true\ninsert_final_newline = true\n",
+    );
+    let file_path = dir.join("file.txt");
+    fs::write(&file_path, "a \nb\t").unwrap();
+
+    assert!(format_file_in_place(&file_path).unwrap());
+    assert_eq!(fs::read(&file_path).unwrap(), b"a\r\nb\r\n");
+
+    // Formatting again doesn't change anything
+    assert!(!format_file_in_place(&file_path).unwrap());
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn format_keeps_charset() {
+    let dir = format_test_dir(
+        "format-charset",
+        "[*.utf16]\ncharset = utf-16le\ninsert_final_newline = true\n\n\
+         [*.latin1]\ncharset = latin1\ninsert_final_newline = true\n\n\
+         [*.bom]\ninsert_final_newline = true\n",
+    );
+
+    let file_path = dir.join("file.utf16");
+    fs::write(&file_path, [0xFF, 0xFE, 0xE9, 0x00]).unwrap();
+    assert!(format_file_in_place(&file_path).unwrap());
+    assert_eq!(
+        fs::read(&file_path).unwrap(),
+        [0xFF, 0xFE, 0xE9, 0x00, b'\n', 0x00]
+    );
+
+    let file_path = dir.join("file.latin1");
+    fs::write(&file_path, [0xE9]).unwrap();
+    assert!(format_file_in_place(&file_path).unwrap());
+    assert_eq!(fs::read(&file_path).unwrap(), [0xE9, b'\n']);
+
+    // Without a charset, a byte order mark is kept
+    let file_path = dir.join("file.bom");
+    fs::write(&file_path, [0xEF, 0xBB, 0xBF, b'a']).unwrap();
+    assert!(format_file_in_place(&file_path).unwrap());
+    assert_eq!(
+        fs::read(&file_path).unwrap(),
+        [0xEF, 0xBB, 0xBF, b'a', b'\n']
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn format_errors() {
+    let dir = format_test_dir("format-errors", "[*]\ncharset = utf-8\n");
+
+    let file_path = dir.join("invalid.txt");
+    fs::write(&file_path, [0xFF]).unwrap();
+    let err = format_file_in_place(&file_path).unwrap_err();
+    assert!(matches!(err, FormatError::Decode));
+    assert_eq!(fs::read(&file_path).unwrap(), [0xFF]);
+
+    let err = format_file_in_place(dir.join("missing.txt")).unwrap_err();
+    assert!(matches!(err, FormatError::Io(_)));
+
+    let err = format_file_in_place("relative.txt").unwrap_err();
+    assert!(matches!(
+        err,
+        FormatError::Parse(ParseError::NotFullPathError)
+    ));
+
+    fs::remove_dir_all(dir).unwrap();
+}
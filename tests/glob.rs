@@ -0,0 +1,120 @@
+use editorconfig_rs::{glob_matches, glob_matches_case_insensitive};
+
+#[test]
+fn star() {
+    assert!(glob_matches("*.rs", "lib.rs"));
+    assert!(glob_matches("*.rs", "src/lib.rs"));
+    assert!(glob_matches("*", "src/lib.rs"));
+    assert!(!glob_matches("*.rs", "lib.rs.bak"));
+    assert!(!glob_matches("src/*.rs", "src/bin/main.rs"));
+}
+
+#[test]
+fn double_star() {
+    assert!(glob_matches("src/**.rs", "src/lib.rs"));
+    assert!(glob_matches("src/**.rs", "src/bin/main.rs"));
+    assert!(glob_matches("src/**/*.rs", "src/lib.rs"));
+    assert!(glob_matches("src/**/*.rs", "src/bin/main.rs"));
+    assert!(!glob_matches("src/**.rs", "tests/glob.rs"));
+}
+
+#[test]
+fn question_mark() {
+    assert!(glob_matches("?.c", "a.c"));
+    assert!(glob_matches("src/?.c", "src/a.c"));
+    assert!(!glob_matches("?.c", "ab.c"));
+    assert!(!glob_matches("src?a.c", "src/a.c"));
+}
+
+#[test]
+fn character_class() {
+    assert!(glob_matches("[abc].c", "b.c"));
+    assert!(glob_matches("[a-c].c", "b.c"));
+    assert!(!glob_matches("[abc].c", "d.c"));
+    assert!(glob_matches("[!abc].c", "d.c"));
+    assert!(!glob_matches("[!abc].c", "a.c"));
+
+    // An unterminated class or one containing a slash is matched literally
+    assert!(glob_matches("[abc.c", "[abc.c"));
+    assert!(glob_matches("a[b/c]d", "a[b/c]d"));
+}
+
+#[test]
+fn alternatives() {
+    assert!(glob_matches("*.{js,ts}", "src/app.js"));
+    assert!(glob_matches("*.{js,ts}", "src/app.ts"));
+    assert!(!glob_matches("*.{js,ts}", "src/app.rs"));
+    assert!(glob_matches("{Makefile,*.mk}", "build/rules.mk"));
+    assert!(glob_matches("*.{j{s,son},ts}", "package.json"));
+
+    // Braces without alternatives are matched literally
+    assert!(glob_matches("{single}.txt", "{single}.txt"));
+    assert!(!glob_matches("{single}.txt", "single.txt"));
+}
+
+#[test]
+fn numeric_range() {
+    assert!(glob_matches("file{1..3}.txt", "file1.txt"));
+    assert!(glob_matches("file{1..3}.txt", "file3.txt"));
+    assert!(!glob_matches("file{1..3}.txt", "file4.txt"));
+    assert!(!glob_matches("file{1..3}.txt", "file12.txt"));
+    assert!(glob_matches("file{-3..3}.txt", "file-2.txt"));
+}
+
+#[test]
+fn anchored_patterns() {
+    assert!(glob_matches("/*.rs", "build.rs"));
+    assert!(!glob_matches("/*.rs", "src/lib.rs"));
+    assert!(glob_matches("/src/*.rs", "src/lib.rs"));
+}
+
+#[test]
+fn escaped_characters() {
+    assert!(glob_matches(r"\*.txt", "*.txt"));
+    assert!(!glob_matches(r"\*.txt", "a.txt"));
+    assert!(glob_matches(r"a\?.txt", "a?.txt"));
+    assert!(!glob_matches(r"a\?.txt", "ab.txt"));
+    assert!(glob_matches(r"\[abc].txt", "[abc].txt"));
+    assert!(!glob_matches(r"\[abc].txt", "a.txt"));
+    assert!(glob_matches(r"\{a,b}.txt", "{a,b}.txt"));
+    assert!(!glob_matches(r"\{a,b}.txt", "a.txt"));
+    assert!(glob_matches(r"{a\},b}.txt", "a}.txt"));
+    assert!(glob_matches(r"{a\,b,c}.txt", "a,b.txt"));
+    assert!(!glob_matches(r"{a\,b,c}.txt", "b.txt"));
+    assert!(glob_matches(r"[\]a].txt", "].txt"));
+    assert!(glob_matches(r"a\\b", r"a\b"));
+}
+
+#[test]
+#[cfg(windows)]
+fn windows_separators() {
+    assert!(glob_matches("src/**.rs", r"src\lib\main.rs"));
+    assert!(glob_matches("*.rs", r"src\lib.rs"));
+    assert!(glob_matches("/src/*.rs", r"src\lib.rs"));
+    assert!(!glob_matches("/*.rs", r"src\lib.rs"));
+}
+
+#[test]
+fn pathological_patterns() {
+    // These take exponential time with a backtracking matcher
+    let pattern = "*a".repeat(30) + "b";
+    assert!(!glob_matches(&pattern, &"a".repeat(100)));
+    assert!(glob_matches(&pattern, &("a".repeat(100) + "b")));
+
+    let pattern = "**a".repeat(30) + "b";
+    assert!(!glob_matches(&pattern, &"a/".repeat(100)));
+
+    let pattern = "{a,*}".repeat(30) + "b";
+    assert!(!glob_matches(&pattern, &"a".repeat(100)));
+    assert!(glob_matches(&pattern, &("a".repeat(100) + "b")));
+}
+
+#[test]
+fn case_insensitive() {
+    assert!(!glob_matches("*.PNG", "image.png"));
+    assert!(glob_matches_case_insensitive("*.PNG", "image.png"));
+    assert!(glob_matches_case_insensitive("*.png", "IMAGE.PNG"));
+    assert!(glob_matches_case_insensitive("[A-C].txt", "b.txt"));
+    assert!(glob_matches_case_insensitive("{Makefile,*.mk}", "MAKEFILE"));
+    assert!(!glob_matches_case_insensitive("*.png", "image.jpg"));
+}
@@ -0,0 +1,33 @@
+use editorconfig_rs::{find_mixed_indentation, Rules};
+use std::collections::HashMap;
+
+fn rules(indent_style: Option<&str>) -> Rules {
+    let mut rules = HashMap::new();
+    if let Some(indent_style) = indent_style {
+        rules.insert("indent_style".to_owned(), indent_style.to_owned());
+    }
+    Rules::from(rules)
+}
+
+#[test]
+fn mixed_indentation() {
+    let content = "a\n\tb\n    c\n\t  d\n  \te\r\n \t\n\t\tf \t g\n";
+    for indent_style in ["tab", "space"] {
+        let lines = find_mixed_indentation(content, &rules(Some(indent_style)));
+        assert_eq!(lines, vec![4, 5, 6]);
+    }
+}
+
+#[test]
+fn consistent_indentation() {
+    let content = "a\n\tb\n\t\tc\n    d\n";
+    assert!(find_mixed_indentation(content, &rules(Some("tab"))).is_empty());
+    assert!(find_mixed_indentation("", &rules(Some("space"))).is_empty());
+}
+
+#[test]
+fn no_indent_style() {
+    let content = "\t  a\n";
+    assert!(find_mixed_indentation(content, &rules(None)).is_empty());
+    assert!(find_mixed_indentation(content, &rules(Some("invalid"))).is_empty());
+}
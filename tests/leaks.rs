@@ -0,0 +1,87 @@
+// Counts the Rust allocations made while creating, using and dropping
+// handles. Memory allocated by libeditorconfig itself isn't counted; check
+// that by running this test binary under `valgrind --leak-check=full`.
+
+use editorconfig_rs::{EditorConfigHandle, ParseError, Version};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fs,
+    path::Path,
+};
+
+struct CountingAllocator;
+
+thread_local! {
+    // Per thread, so allocations by other test threads aren't counted
+    static ALLOCATED_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATED_BYTES.try_with(|bytes| bytes.set(bytes.get() + layout.size() as isize));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = ALLOCATED_BYTES.try_with(|bytes| bytes.set(bytes.get() - layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 1000;
+
+fn allocated_bytes() -> isize {
+    ALLOCATED_BYTES.with(|bytes| bytes.get())
+}
+
+fn assert_no_leaks<F: Fn()>(f: F) {
+    // Warm up once so one-time allocations aren't counted as leaks
+    f();
+
+    let allocated_bytes_before = allocated_bytes();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    assert_eq!(allocated_bytes(), allocated_bytes_before);
+}
+
+fn parse_and_get_rules(test_file_path: &Path) {
+    let mut handle = EditorConfigHandle::new().unwrap();
+    handle.set_config_filename(".editorconfig");
+
+    let err = handle.parse(test_file_path);
+    assert!(err.is_none());
+
+    let rules = handle.get_rules();
+    assert_eq!(rules.len(), 4);
+}
+
+#[test]
+fn handle_lifecycle() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    assert_no_leaks(|| parse_and_get_rules(&test_file_path));
+}
+
+#[test]
+fn handle_lifecycle_with_errors() {
+    let test_file_path = fs::canonicalize(file!()).unwrap();
+    assert_no_leaks(|| {
+        let mut handle = EditorConfigHandle::builder()
+            .config_filename(".editorconfig.invalid")
+            .build()
+            .unwrap();
+
+        let err = handle.parse(&test_file_path).unwrap();
+        assert_eq!(err, ParseError::LineError(3));
+        assert!(handle.get_error_file().is_some());
+
+        handle.set_version(Version::new(i32::MAX, 0, 0));
+        handle.clear_config_filename();
+        let err = handle.parse(&test_file_path).unwrap();
+        assert_eq!(err, ParseError::VersionTooNewError);
+    });
+}
@@ -0,0 +1,98 @@
+use editorconfig_rs::{line_ending_report, normalize_line_endings, EndOfLine, LineEndingReport};
+
+#[test]
+fn no_line_endings() {
+    let report = line_ending_report("no line endings");
+    assert_eq!(report, LineEndingReport::default());
+    assert_eq!(report.total(), 0);
+    assert_eq!(report.dominant(), None);
+    assert!(!report.is_mixed());
+}
+
+#[test]
+fn single_kind_of_line_endings() {
+    let report = line_ending_report("a\r\nb\r\n");
+    assert_eq!(report.crlf, 2);
+    assert_eq!(report.count(EndOfLine::Lf), 0);
+    assert_eq!(report.dominant(), Some(EndOfLine::Crlf));
+    assert!(!report.is_mixed());
+
+    let report = line_ending_report("a\rb\rc");
+    assert_eq!(report.cr, 2);
+    assert_eq!(report.dominant(), Some(EndOfLine::Cr));
+}
+
+#[test]
+fn mixed_line_endings() {
+    let report = line_ending_report("a\nb\r\nc\rd\n\r\n");
+    assert_eq!(
+        report,
+        LineEndingReport {
+            lf: 2,
+            crlf: 2,
+            cr: 1
+        }
+    );
+    assert_eq!(report.total(), 5);
+    assert!(report.is_mixed());
+
+    // Ties are resolved in favor of LF, then CRLF
+    assert_eq!(report.dominant(), Some(EndOfLine::Lf));
+    assert_eq!(
+        line_ending_report("\r\r\n").dominant(),
+        Some(EndOfLine::Crlf)
+    );
+}
+
+#[test]
+fn lone_carriage_return_before_line_feed() {
+    // `\r\r\n` is a CR followed by a CRLF
+    let report = line_ending_report("a\r\r\nb");
+    assert_eq!(report.cr, 1);
+    assert_eq!(report.crlf, 1);
+    assert_eq!(report.lf, 0);
+}
+
+#[test]
+fn normalize_each_line_ending() {
+    let sources = ["a\nb\n", "a\r\nb\r\n", "a\rb\r"];
+    let targets = [
+        (EndOfLine::Lf, "a\nb\n"),
+        (EndOfLine::Crlf, "a\r\nb\r\n"),
+        (EndOfLine::Cr, "a\rb\r"),
+    ];
+
+    for source in sources {
+        for (end_of_line, expected) in targets {
+            assert_eq!(normalize_line_endings(source, end_of_line), expected);
+        }
+    }
+}
+
+#[test]
+fn normalize_mixed_line_endings() {
+    let content = "a\nb\r\nc\rd\r\r\ne\n\rf";
+    assert_eq!(
+        normalize_line_endings(content, EndOfLine::Lf),
+        "a\nb\nc\nd\n\ne\n\nf"
+    );
+    assert_eq!(
+        normalize_line_endings(content, EndOfLine::Crlf),
+        "a\r\nb\r\nc\r\nd\r\n\r\ne\r\n\r\nf"
+    );
+    assert_eq!(
+        normalize_line_endings(content, EndOfLine::Cr),
+        "a\rb\rc\rd\r\re\r\rf"
+    );
+
+    let normalized = normalize_line_endings(content, EndOfLine::Crlf);
+    let report = line_ending_report(&normalized);
+    assert_eq!(report.total(), line_ending_report(content).total());
+    assert!(!report.is_mixed());
+}
+
+#[test]
+fn normalize_without_line_endings() {
+    assert_eq!(normalize_line_endings("", EndOfLine::Crlf), "");
+    assert_eq!(normalize_line_endings("🦀", EndOfLine::Crlf), "🦀");
+}
@@ -0,0 +1,230 @@
+use editorconfig_rs::{
+    properties::{names, KNOWN_PROPERTIES},
+    Charset, EditorConfigHandle, EndOfLine, IndentStyle, PropertyError, Rules,
+};
+use std::{collections::HashMap, fs};
+
+#[test]
+fn indent_style_from_str() {
+    assert_eq!("tab".parse(), Ok(IndentStyle::Tab));
+    assert_eq!("Space".parse(), Ok(IndentStyle::Space));
+    assert_eq!(IndentStyle::try_from("SPACE"), Ok(IndentStyle::Space));
+
+    let err = IndentStyle::try_from("tabs").unwrap_err();
+    assert_eq!(
+        err,
+        PropertyError {
+            name: "indent_style",
+            value: "tabs".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "Invalid indent_style value: tabs");
+}
+
+#[test]
+fn end_of_line_from_str() {
+    assert_eq!("lf".parse(), Ok(EndOfLine::Lf));
+    assert_eq!("CRLF".parse(), Ok(EndOfLine::Crlf));
+    assert_eq!(EndOfLine::try_from("cr"), Ok(EndOfLine::Cr));
+
+    let err = EndOfLine::try_from("\n").unwrap_err();
+    assert_eq!(err.name, "end_of_line");
+    assert_eq!(err.value, "\n");
+}
+
+#[test]
+fn charset_from_str() {
+    assert_eq!("latin1".parse(), Ok(Charset::Latin1));
+    assert_eq!("utf-8".parse(), Ok(Charset::Utf8));
+    assert_eq!("UTF-8-BOM".parse(), Ok(Charset::Utf8Bom));
+    assert_eq!(Charset::try_from("utf-16be"), Ok(Charset::Utf16Be));
+    assert_eq!(Charset::try_from("utf-16le"), Ok(Charset::Utf16Le));
+
+    let err = Charset::try_from("utf8").unwrap_err();
+    assert_eq!(err.name, "charset");
+    assert_eq!(err.to_string(), "Invalid charset value: utf8");
+}
+
+#[test]
+fn property_names() {
+    let mut rules = HashMap::new();
+    rules.insert(names::INDENT_STYLE.to_string(), "tab".to_string());
+    rules.insert(names::END_OF_LINE.to_string(), "crlf".to_string());
+    let rules = Rules::from(rules);
+
+    assert_eq!(rules.indent_style(), Some(IndentStyle::Tab));
+    assert_eq!(rules.end_of_line(), Some(EndOfLine::Crlf));
+    assert_eq!(rules.get(names::END_OF_LINE).unwrap(), "crlf");
+    assert_eq!(rules.get(names::CHARSET), None);
+}
+
+#[test]
+fn mutate_rules_like_a_map() {
+    let mut rules = Rules::default();
+    rules.insert(names::INDENT_STYLE.to_string(), "space".to_string());
+    assert_eq!(rules.indent_style(), Some(IndentStyle::Space));
+
+    rules
+        .as_mut()
+        .insert(names::INDENT_STYLE.to_string(), "tab".to_string());
+    assert_eq!(rules.indent_style(), Some(IndentStyle::Tab));
+
+    let mut expected = HashMap::new();
+    expected.insert(names::INDENT_STYLE.to_string(), "tab".to_string());
+    assert_eq!(expected, rules);
+    assert_eq!(rules, expected);
+}
+
+#[test]
+fn try_property_values() {
+    let mut rules = HashMap::new();
+    rules.insert(names::INDENT_STYLE.to_string(), "space".to_string());
+    rules.insert(names::END_OF_LINE.to_string(), "invalid".to_string());
+    let rules = Rules::from(rules);
+
+    assert_eq!(rules.try_indent_style(), Ok(Some(IndentStyle::Space)));
+    assert_eq!(
+        rules.try_end_of_line(),
+        Err(PropertyError {
+            name: names::END_OF_LINE,
+            value: "invalid".to_string()
+        })
+    );
+    assert_eq!(rules.end_of_line(), None);
+
+    let mut charset = HashMap::new();
+    charset.insert(names::CHARSET.to_string(), "utf-8-bom".to_string());
+    let rules = Rules::from(charset);
+    assert_eq!(rules.try_charset(), Ok(Some(Charset::Utf8Bom)));
+    assert_eq!(rules.charset(), Some(Charset::Utf8Bom));
+
+    let rules = Rules::default();
+    assert_eq!(rules.try_charset(), Ok(None));
+    assert_eq!(rules.try_indent_style(), Ok(None));
+    assert_eq!(rules.try_end_of_line(), Ok(None));
+}
+
+#[test]
+fn strict_widths() {
+    let width = |value: &str| {
+        let mut rules = HashMap::new();
+        rules.insert(names::INDENT_SIZE.to_string(), value.to_string());
+        rules.insert(names::TAB_WIDTH.to_string(), value.to_string());
+        let rules = Rules::from(rules);
+        assert_eq!(rules.indent_size(), rules.tab_width());
+        rules.tab_width()
+    };
+
+    assert_eq!(width("4"), Some(4));
+    assert_eq!(width("120"), Some(120));
+    assert_eq!(width("4294967295"), Some(u32::MAX));
+    for value in [
+        "",
+        "0",
+        "04",
+        "+4",
+        "-4",
+        " 4",
+        "4 ",
+        "4.0",
+        "tab",
+        "４",
+        "4294967296",
+    ] {
+        assert_eq!(width(value), None, "{:?}", value);
+    }
+    assert_eq!(Rules::default().indent_size(), None);
+}
+
+#[test]
+fn effective_widths() {
+    // (indent_style, indent_size, tab_width) => (indent size, tab width)
+    let expected = [
+        ((None, None, None), (None, None)),
+        ((None, None, Some("8")), (None, Some(8))),
+        ((None, Some("2"), None), (Some(2), Some(2))),
+        ((None, Some("2"), Some("8")), (Some(2), Some(8))),
+        ((None, Some("tab"), None), (None, None)),
+        ((None, Some("tab"), Some("8")), (Some(8), Some(8))),
+        ((Some("space"), None, None), (None, None)),
+        ((Some("space"), None, Some("8")), (None, Some(8))),
+        ((Some("space"), Some("2"), None), (Some(2), Some(2))),
+        ((Some("space"), Some("2"), Some("8")), (Some(2), Some(8))),
+        ((Some("space"), Some("tab"), None), (None, None)),
+        ((Some("space"), Some("tab"), Some("8")), (Some(8), Some(8))),
+        ((Some("tab"), None, None), (None, None)),
+        ((Some("tab"), None, Some("8")), (Some(8), Some(8))),
+        ((Some("tab"), Some("2"), None), (Some(2), Some(2))),
+        ((Some("tab"), Some("2"), Some("8")), (Some(2), Some(8))),
+        ((Some("tab"), Some("tab"), None), (None, None)),
+        ((Some("tab"), Some("tab"), Some("8")), (Some(8), Some(8))),
+    ];
+
+    for ((indent_style, indent_size, tab_width), expected) in expected {
+        let mut rules = HashMap::new();
+        let properties = [
+            (names::INDENT_STYLE, indent_style),
+            (names::INDENT_SIZE, indent_size),
+            (names::TAB_WIDTH, tab_width),
+        ];
+        for (name, value) in properties {
+            if let Some(value) = value {
+                rules.insert(name.to_string(), value.to_string());
+            }
+        }
+        let rules = Rules::from(rules);
+
+        assert_eq!(
+            (rules.effective_indent_size(), rules.effective_tab_width()),
+            expected,
+            "{:?}",
+            rules
+        );
+    }
+}
+
+#[test]
+fn values_longer_than() {
+    let mut rules = HashMap::new();
+    rules.insert(names::CHARSET.to_string(), "utf-8".to_string());
+    rules.insert("custom_b".to_string(), "x".repeat(100));
+    rules.insert("custom_a".to_string(), "x".repeat(11));
+    let rules = Rules::from(rules);
+
+    assert_eq!(rules.values_longer_than(10), ["custom_a", "custom_b"]);
+    assert_eq!(rules.values_longer_than(11), ["custom_b"]);
+    assert!(rules.values_longer_than(100).is_empty());
+}
+
+#[test]
+fn ineffective_tab_width() {
+    let rules_test_dir = fs::canonicalize("tests/rules").unwrap();
+    let handle = EditorConfigHandle::new().unwrap();
+
+    // libeditorconfig sets `tab_width` to a numeric `indent_size` itself
+    let expected = [
+        ("file.space", false),
+        ("file.space_size", false),
+        ("file.space_tab_width", true),
+        ("file.space_size_tab_width", true),
+        ("file.tab_tab_width", false),
+        ("file.rs", false),
+    ];
+    for (filename, expected) in expected {
+        let err = handle.parse_dir_for(&rules_test_dir, filename);
+        assert!(err.is_none());
+        assert_eq!(
+            handle.get_rules().has_ineffective_tab_width(),
+            expected,
+            "{}",
+            filename
+        );
+    }
+}
+
+#[test]
+fn known_properties() {
+    assert!(KNOWN_PROPERTIES.contains(&names::INDENT_STYLE));
+    assert!(KNOWN_PROPERTIES.contains(&names::MAX_LINE_LENGTH));
+    assert!(!KNOWN_PROPERTIES.contains(&names::ROOT));
+}
@@ -0,0 +1,175 @@
+//! Hand-written cases for globs, property normalization, parsing and
+//! precedence
+//!
+//! Each case parses a target path relative to `tests/spec` with a fixture
+//! config file and compares the resolved rules with the expected ones. For
+//! the glob cases, [`glob_matches`] must also agree with `libeditorconfig` on
+//! which sections match.
+
+use editorconfig_rs::{glob_matches, parse_config_document, EditorConfigHandle, Rules};
+use std::{collections::HashMap, fs, path::Path};
+
+struct TestCase {
+    config_filename: &'static str,
+    target: &'static str,
+    expected: &'static [(&'static str, &'static str)],
+}
+
+const TEST_CASES: &[TestCase] = &[
+    // Globs
+    TestCase {
+        config_filename: "glob.in",
+        target: "abcde.c",
+        expected: &[("star", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "ae.c",
+        expected: &[("star", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "abe.c",
+        expected: &[("star", "true"), ("question_mark", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "sub/ae.c",
+        expected: &[("star", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "a/e.c",
+        expected: &[],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "x.brackets",
+        expected: &[("brackets", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "w.brackets",
+        expected: &[],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "word.braces",
+        expected: &[("braces", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "other.braces",
+        expected: &[("braces", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "num4.range",
+        expected: &[("range", "true")],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "num6.range",
+        expected: &[],
+    },
+    TestCase {
+        config_filename: "glob.in",
+        target: "a/b/c.deep",
+        expected: &[("double_star", "true")],
+    },
+    // Properties
+    TestCase {
+        config_filename: "properties.in",
+        target: "indent_style_tab.txt",
+        expected: &[("indent_style", "tab"), ("indent_size", "tab")],
+    },
+    TestCase {
+        config_filename: "properties.in",
+        target: "indent_size.txt",
+        expected: &[("indent_size", "4"), ("tab_width", "4")],
+    },
+    TestCase {
+        config_filename: "properties.in",
+        target: "lowercase.txt",
+        expected: &[
+            ("indent_style", "space"),
+            ("end_of_line", "crlf"),
+            ("custom_key", "MixedCase"),
+        ],
+    },
+    // Parser
+    TestCase {
+        config_filename: "parser.in",
+        target: "whitespace.txt",
+        expected: &[
+            ("key1", "value1"),
+            ("key2", "value2"),
+            ("key3", "value with spaces"),
+        ],
+    },
+    // Precedence
+    TestCase {
+        config_filename: "precedence.in",
+        target: "precedence.txt",
+        expected: &[("key", "second")],
+    },
+    TestCase {
+        config_filename: "precedence.in",
+        target: "nested/override.txt",
+        expected: &[("key", "nested"), ("nested_key", "nested")],
+    },
+    TestCase {
+        config_filename: "precedence.in",
+        target: "nested/other.txt",
+        expected: &[("key", "first"), ("nested_key", "nested")],
+    },
+];
+
+#[test]
+fn spec_cases() {
+    let spec_dir = fs::canonicalize("tests/spec").unwrap();
+    let mut handle = EditorConfigHandle::new().unwrap();
+
+    for test_case in TEST_CASES {
+        handle.set_config_filename(test_case.config_filename);
+        let err = handle.parse(spec_dir.join(test_case.target));
+        assert!(err.is_none(), "{}: {:?}", test_case.target, err);
+
+        let expected: HashMap<String, String> = test_case
+            .expected
+            .iter()
+            .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+            .collect();
+        let rules = handle.get_rules();
+        assert_eq!(
+            rules, expected,
+            "{} with {}",
+            test_case.target, test_case.config_filename
+        );
+
+        if test_case.config_filename == "glob.in" {
+            assert_glob_matches_agree(&spec_dir.join("glob.in"), test_case.target, &rules);
+        }
+    }
+}
+
+/// Asserts that [`glob_matches`] matches exactly the sections of a glob
+/// fixture whose properties `libeditorconfig` applied to `target`
+///
+/// Every section in the glob fixture sets a property no other section sets.
+fn assert_glob_matches_agree(config_file_path: &Path, target: &str, rules: &Rules) {
+    let config = fs::read_to_string(config_file_path).unwrap();
+    for section in parse_config_document(&config).sections {
+        let libeditorconfig_matches = section
+            .properties
+            .iter()
+            .all(|property| rules.get(&property.key) == Some(&property.value));
+        assert_eq!(
+            glob_matches(&section.glob, target),
+            libeditorconfig_matches,
+            "[{}] with {}",
+            section.glob,
+            target
+        );
+    }
+}
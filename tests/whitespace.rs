@@ -0,0 +1,177 @@
+use editorconfig_rs::{normalize_reader, normalize_whitespace_only, properties::names, Rules};
+use rand::{seq::SliceRandom, Rng};
+use std::{
+    collections::HashMap,
+    io::{self, BufReader},
+};
+
+fn rules(rules: &[(&str, &str)]) -> Rules {
+    rules
+        .iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect::<HashMap<_, _>>()
+        .into()
+}
+
+#[test]
+fn no_rules() {
+    let content = "a \r\n\tb\t\n";
+    assert_eq!(
+        normalize_whitespace_only(content, &Rules::default()),
+        content
+    );
+}
+
+#[test]
+fn trim_trailing_whitespace() {
+    let rules = rules(&[(names::TRIM_TRAILING_WHITESPACE, "true")]);
+    let content = "a \r\n\tb\t\n  \n\u{a0}c \u{3000}\rd  ";
+    assert_eq!(
+        normalize_whitespace_only(content, &rules),
+        "a\r\n\tb\n\n\u{a0}c\rd"
+    );
+
+    let rules = self::rules(&[(names::TRIM_TRAILING_WHITESPACE, "false")]);
+    assert_eq!(normalize_whitespace_only("a \n", &rules), "a \n");
+}
+
+#[test]
+fn insert_final_newline() {
+    let rules = rules(&[(names::INSERT_FINAL_NEWLINE, "true")]);
+    assert_eq!(normalize_whitespace_only("a", &rules), "a\n");
+    assert_eq!(normalize_whitespace_only("a\r\nb", &rules), "a\r\nb\r\n");
+    assert_eq!(normalize_whitespace_only("a\n", &rules), "a\n");
+    assert_eq!(normalize_whitespace_only("", &rules), "");
+
+    let rules = self::rules(&[(names::INSERT_FINAL_NEWLINE, "false")]);
+    assert_eq!(normalize_whitespace_only("a\n\r\n", &rules), "a");
+    assert_eq!(normalize_whitespace_only("a", &rules), "a");
+}
+
+#[test]
+fn end_of_line() {
+    let rules = rules(&[
+        (names::END_OF_LINE, "crlf"),
+        (names::TRIM_TRAILING_WHITESPACE, "true"),
+        (names::INSERT_FINAL_NEWLINE, "true"),
+    ]);
+    assert_eq!(
+        normalize_whitespace_only("a \nb\t\rc ", &rules),
+        "a\r\nb\r\nc\r\n"
+    );
+}
+
+#[test]
+fn indentation_untouched() {
+    let rules = rules(&[
+        (names::INDENT_STYLE, "space"),
+        (names::INDENT_SIZE, "4"),
+        (names::TRIM_TRAILING_WHITESPACE, "true"),
+    ]);
+    assert_eq!(
+        normalize_whitespace_only("\tif a:\n\t  b \n", &rules),
+        "\tif a:\n\t  b\n"
+    );
+}
+
+/// Rules for every combination of the whitespace-related properties being
+/// unset, valid or invalid
+fn all_rule_combinations() -> Vec<Rules> {
+    let mut combinations = Vec::new();
+    for end_of_line in [None, Some("lf"), Some("crlf"), Some("cr"), Some("invalid")] {
+        for trim in [None, Some("true"), Some("false")] {
+            for insert in [None, Some("true"), Some("false")] {
+                let properties = [
+                    (names::END_OF_LINE, end_of_line),
+                    (names::TRIM_TRAILING_WHITESPACE, trim),
+                    (names::INSERT_FINAL_NEWLINE, insert),
+                ];
+                let properties: Vec<(&str, &str)> = properties
+                    .iter()
+                    .filter_map(|&(name, value)| Some((name, value?)))
+                    .collect();
+                combinations.push(rules(&properties));
+            }
+        }
+    }
+    combinations
+}
+
+const EDGE_CASES: &[&str] = &[
+    "",
+    "a",
+    " ",
+    " \t ",
+    "\n",
+    "\r\n\r\n",
+    "\r\r\n\n",
+    "a\n\n\n",
+    "a \r\n \r\n\t\r\n",
+    "a\rb\r\nc\nd",
+    "\u{a0}é \n\u{3000}",
+    "  \n\n  ",
+];
+
+#[test]
+fn normalize_reader_matches_normalize_whitespace_only() {
+    for rules in all_rule_combinations() {
+        for &content in EDGE_CASES {
+            let expected = normalize_whitespace_only(content, &rules);
+
+            // A one byte buffer splits the content at every possible position
+            let reader = BufReader::with_capacity(1, content.as_bytes());
+            let mut normalized = Vec::new();
+            normalize_reader(reader, &mut normalized, &rules).unwrap();
+            assert_eq!(
+                String::from_utf8(normalized).unwrap(),
+                expected,
+                "{:?} with {:?}",
+                content,
+                rules
+            );
+        }
+    }
+}
+
+#[test]
+fn normalize_reader_invalid_utf8() {
+    let mut normalized = Vec::new();
+    let err = normalize_reader(&b"a\n\xff\n"[..], &mut normalized, &Rules::default()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn idempotent() {
+    let mut rng = rand::thread_rng();
+    let alphabet = ["a", " ", "\t", "\n", "\r", "\r\n", "\u{a0}"];
+    let mut contents: Vec<String> = EDGE_CASES
+        .iter()
+        .map(|&content| content.to_owned())
+        .collect();
+    for _ in 0..200 {
+        let len = rng.gen_range(0..12);
+        let content = (0..len)
+            .map(|_| *alphabet.choose(&mut rng).unwrap())
+            .collect();
+        contents.push(content);
+    }
+
+    for rules in all_rule_combinations() {
+        for content in &contents {
+            let once = normalize_whitespace_only(content, &rules);
+            let twice = normalize_whitespace_only(&once, &rules);
+            assert_eq!(once, twice, "{:?} with {:?}", content, rules);
+        }
+    }
+}
+
+#[test]
+fn whitespace_only_content() {
+    let rules = rules(&[
+        (names::TRIM_TRAILING_WHITESPACE, "true"),
+        (names::INSERT_FINAL_NEWLINE, "true"),
+    ]);
+    assert_eq!(normalize_whitespace_only(" \t ", &rules), "");
+    assert_eq!(normalize_whitespace_only(" \n\t", &rules), "\n");
+    assert_eq!(normalize_whitespace_only("\r\n \r\n", &rules), "\r\n\r\n");
+}